@@ -2,6 +2,25 @@ use std::ffi::c_void;
 
 pub mod utf16;
 
+/// Returns the string object shared by all `ns_string!` uses with the same
+/// contents as `s`.
+#[cfg(feature = "ns_string_intern")]
+#[inline]
+pub fn intern(
+    s: &'static crate::foundation::NSString<'static>,
+) -> &'static crate::foundation::NSString<'static> {
+    s.intern()
+}
+
+/// Returns `s` as-is because interning is disabled.
+#[cfg(not(feature = "ns_string_intern"))]
+#[inline]
+pub const fn intern(
+    s: &'static crate::foundation::NSString<'static>,
+) -> &'static crate::foundation::NSString<'static> {
+    s
+}
+
 // From `CFString.c`:
 // > !!! Note: Constant CFStrings use the bit patterns:
 // > C8 (11001000 = default allocator, not inline, not freed contents; 8-bit; has NULL byte; doesn't have length; is immutable)
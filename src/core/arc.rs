@@ -1,5 +1,6 @@
 use super::ObjectType;
 use std::{
+    error::Error,
     fmt,
     hash::{Hash, Hasher},
     mem::{self, ManuallyDrop},
@@ -7,6 +8,11 @@ use std::{
     ptr::NonNull,
 };
 
+#[cfg(feature = "objc")]
+use crate::objc;
+#[cfg(feature = "objc")]
+use std::{cell::UnsafeCell, marker::PhantomData, ptr};
+
 // TODO: Implement infallible `Arc` casting for class chain.
 
 /// A thread-safe automatically-reference-counted pointer to an object.
@@ -72,6 +78,32 @@ impl<T: ObjectType + fmt::Debug> fmt::Debug for Arc<T> {
     }
 }
 
+impl<T: ObjectType + Error> Error for Arc<T> {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        (**self).source()
+    }
+}
+
+/// Forwards radix formatting traits to the object.
+macro_rules! forward_radix_fmt {
+    ($($trait:ident,)+) => {$(
+        impl<T: ObjectType + fmt::$trait> fmt::$trait for Arc<T> {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                (**self).fmt(f)
+            }
+        }
+    )+};
+}
+
+forward_radix_fmt! {
+    Binary,
+    Octal,
+    LowerHex,
+    UpperHex,
+}
+
 impl<T: ObjectType> fmt::Pointer for Arc<T> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -121,6 +153,38 @@ impl<T: ObjectType> Arc<T> {
         Self::retain(&ManuallyDrop::new(Self::from_raw(obj)))
     }
 
+    /// Constructs an `Arc<T>` from a non-null pointer, without retaining it.
+    ///
+    /// This is the inverse of [`Arc::as_non_null`] combined with
+    /// [`mem::forget`], or of [`Arc::into_raw`].
+    ///
+    /// # Safety
+    ///
+    /// The same requirements as [`Arc::from_raw`] apply.
+    #[inline]
+    pub unsafe fn from_non_null(obj: NonNull<T>) -> Self {
+        Self { obj }
+    }
+
+    /// Returns the wrapped pointer without affecting the reference count.
+    ///
+    /// This is zero-cost because `Arc<T>` is `#[repr(transparent)]` over a
+    /// [`NonNull<T>`]. The pointer is only guaranteed to be valid for as long
+    /// as `this` is alive.
+    #[inline]
+    pub fn as_non_null(this: &Self) -> NonNull<T> {
+        this.obj
+    }
+
+    /// Returns `true` if both `Arc`s point to the same object.
+    ///
+    /// Unlike [`PartialEq`], this compares object identity rather than
+    /// contents. This is similar to [`std::sync::Arc::ptr_eq`].
+    #[inline]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        this.obj == other.obj
+    }
+
     /// Consumes the `Arc`, returning the wrapped pointer.
     ///
     /// To avoid a memory leak, the pointer must be converted back to an `Arc`
@@ -143,4 +207,328 @@ impl<T: ObjectType> Arc<T> {
     pub unsafe fn cast_unchecked<U: ObjectType>(this: Self) -> Arc<U> {
         Arc::from_raw(Self::into_raw(this).cast())
     }
+
+    /// Reinterprets a slice of `Arc`s as a slice of raw object pointers.
+    ///
+    /// This is useful for calling C APIs that take a `const id *objects`
+    /// parameter alongside a count. No objects are retained, so the pointers
+    /// are only valid for as long as `slice` is borrowed.
+    ///
+    /// This is zero-cost because `Arc<T>` is `#[repr(transparent)]` over a
+    /// [`NonNull<T>`], which has the same layout as `*const T`.
+    #[inline]
+    pub fn slice_as_ptr_array(slice: &[Self]) -> &[*const T] {
+        // SAFETY: `Arc<T>` has the same size, alignment, and validity
+        // invariants as a non-null `*const T`, so the slice can be viewed as a
+        // slice of pointers with the same length and lifetime.
+        unsafe { &*(slice as *const [Self] as *const [*const T]) }
+    }
+}
+
+/// A marker for object types that can be safely shared and sent across
+/// threads.
+///
+/// This is used by [`SendArc`] to move objects across threads without
+/// `unsafe` at the call site.
+///
+/// # Safety
+///
+/// All methods of the type must be safe to call concurrently from multiple
+/// threads, such as for [`NSNumber`](crate::foundation::NSNumber).
+///
+/// This must not be implemented for types with mutable subclasses, such as
+/// [`NSString`](crate::foundation::NSString). A mutable instance can be
+/// referenced through its immutable superclass in safe code, so it could
+/// otherwise be read from one thread while being mutated on another.
+pub unsafe trait ThreadSafe: ObjectType {}
+
+/// An [`Arc`] that can be sent across threads because its object type is known
+/// to be [`ThreadSafe`].
+#[repr(transparent)]
+pub struct SendArc<T: ThreadSafe>(Arc<T>);
+
+// SAFETY: `T` is known to be thread-safe.
+unsafe impl<T: ThreadSafe> Send for SendArc<T> {}
+unsafe impl<T: ThreadSafe> Sync for SendArc<T> {}
+
+impl<T: ThreadSafe> Clone for SendArc<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: ThreadSafe> Deref for SendArc<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: ThreadSafe> AsRef<T> for SendArc<T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T: ThreadSafe> From<Arc<T>> for SendArc<T> {
+    #[inline]
+    fn from(arc: Arc<T>) -> Self {
+        Self::new(arc)
+    }
+}
+
+impl<T: ThreadSafe + fmt::Display> fmt::Display for SendArc<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: ThreadSafe + fmt::Debug> fmt::Debug for SendArc<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: ThreadSafe> SendArc<T> {
+    /// Wraps `arc` to allow sending it across threads.
+    #[inline]
+    pub fn new(arc: Arc<T>) -> Self {
+        Self(arc)
+    }
+
+    /// Returns the wrapped `Arc`.
+    #[inline]
+    pub fn into_inner(this: Self) -> Arc<T> {
+        this.0
+    }
+}
+
+/// A weak reference to an Objective-C object, which does not keep the object
+/// alive.
+///
+/// This is created by [`Arc::downgrade`] and is the counterpart to
+/// [`std::sync::Weak`]. Weak references are useful for breaking reference
+/// cycles, such as between an object and its delegate.
+///
+/// Note that some objects are never deallocated, such as tagged pointers and
+/// singletons, so weak references to them can always be upgraded.
+///
+/// See [documentation](https://developer.apple.com/documentation/objectivec/1418814-objc_storeweak?language=objc).
+#[cfg(feature = "objc")]
+pub struct Weak<T: ObjectType> {
+    // The runtime tracks weak references by the address of this slot, so it
+    // must not move.
+    slot: Box<UnsafeCell<*mut objc::ObjCObject<'static>>>,
+    marker: PhantomData<Arc<T>>,
+}
+
+// SAFETY: The runtime synchronizes access to weak references.
+#[cfg(feature = "objc")]
+unsafe impl<T: ObjectType + Send + Sync> Send for Weak<T> {}
+#[cfg(feature = "objc")]
+unsafe impl<T: ObjectType + Send + Sync> Sync for Weak<T> {}
+
+#[cfg(feature = "objc")]
+impl<T: ObjectType> Drop for Weak<T> {
+    #[inline]
+    #[doc(alias = "objc_destroyWeak")]
+    fn drop(&mut self) {
+        unsafe { objc::sys::objc_destroyWeak(self.slot.get()) };
+    }
+}
+
+#[cfg(feature = "objc")]
+impl<T: ObjectType> Clone for Weak<T> {
+    #[inline]
+    #[doc(alias = "objc_copyWeak")]
+    fn clone(&self) -> Self {
+        let clone = Self::empty();
+        unsafe { objc::sys::objc_copyWeak(clone.slot.get(), self.slot.get()) };
+        clone
+    }
+}
+
+#[cfg(feature = "objc")]
+impl<T: ObjectType> Default for Weak<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "objc")]
+impl<T: ObjectType> fmt::Debug for Weak<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("(Weak)")
+    }
+}
+
+#[cfg(feature = "objc")]
+impl<T: ObjectType> Weak<T> {
+    #[inline]
+    fn empty() -> Self {
+        Self {
+            slot: Box::new(UnsafeCell::new(ptr::null_mut())),
+            marker: PhantomData,
+        }
+    }
+
+    /// Creates a weak reference that never upgrades to an object.
+    #[inline]
+    pub fn new() -> Self {
+        Self::empty()
+    }
+
+    /// Returns a strong reference to the object if it has not been
+    /// deallocated.
+    #[inline]
+    #[doc(alias = "objc_loadWeakRetained")]
+    pub fn upgrade(&self) -> Option<Arc<T>> {
+        // SAFETY: The object is returned retained, and it is only non-null if
+        // it was stored from an instance of `T`.
+        unsafe {
+            let obj = objc::sys::objc_loadWeakRetained(self.slot.get());
+            NonNull::new(obj.cast::<T>()).map(|obj| Arc::from_non_null(obj))
+        }
+    }
+}
+
+#[cfg(feature = "objc")]
+impl<'data, T: objc::ObjectType<'data>> Arc<T> {
+    /// Returns `true` if `this` is the only strong reference to its object.
+    ///
+    /// When this returns `true`, a copy-on-write type may mutate the object in
+    /// place instead of copying it. This mirrors the uniqueness check of
+    /// [`std::sync::Arc::get_mut`].
+    ///
+    /// This is based on
+    /// [`retainCount`](https://developer.apple.com/documentation/objectivec/1418956-nsobject/1571952-retaincount)
+    /// and is only advisory. Tagged pointers, singletons, and objects pending
+    /// in an autorelease pool report counts that make this return `false`.
+    /// References that do not retain the object, such as weak references, are
+    /// not counted.
+    #[inline]
+    #[doc(alias = "retainCount")]
+    pub fn is_unique(this: &Self) -> bool {
+        this.as_objc_object().retain_count() == 1
+    }
+
+    /// Creates a [`Weak`] reference to the object of `this`.
+    #[inline]
+    #[doc(alias = "objc_initWeak")]
+    pub fn downgrade(this: &Self) -> Weak<T> {
+        let weak = Weak::empty();
+        unsafe { objc::sys::objc_initWeak(weak.slot.get(), this.as_objc_object()) };
+        weak
+    }
+}
+
+#[cfg(all(test, feature = "foundation"))]
+mod tests {
+    use super::*;
+    use crate::foundation::{NSArray, NSNumber, NSString};
+    use crate::objc::NSObject;
+
+    #[test]
+    fn send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<SendArc<NSNumber>>();
+
+        #[cfg(feature = "dispatch")]
+        assert_send_sync::<SendArc<crate::dispatch::DispatchQueue>>();
+    }
+
+    #[test]
+    fn non_null_round_trip() {
+        let obj = Arc::<NSObject>::default();
+        let count = obj.retain_count();
+
+        let ptr = Arc::as_non_null(&obj);
+        assert_eq!(obj.retain_count(), count);
+
+        // Transfer ownership through the pointer.
+        mem::forget(obj);
+        let obj = unsafe { Arc::from_non_null(ptr) };
+        assert_eq!(Arc::as_non_null(&obj), ptr);
+        assert_eq!(obj.retain_count(), count);
+    }
+
+    #[test]
+    fn is_unique() {
+        let obj = Arc::<NSObject>::default();
+        assert!(Arc::is_unique(&obj));
+
+        let clone = obj.clone();
+        assert!(!Arc::is_unique(&obj));
+        assert!(!Arc::is_unique(&clone));
+
+        drop(clone);
+        assert!(Arc::is_unique(&obj));
+    }
+
+    #[test]
+    fn ptr_eq() {
+        let a = NSString::from_str("fruity 🦀 identity");
+        let b = a.clone();
+        assert!(Arc::ptr_eq(&a, &b));
+
+        // Equal contents in separate objects.
+        let c = NSString::from_str("fruity 🦀 identity");
+        assert!(*a == *c);
+        assert!(!Arc::ptr_eq(&a, &c));
+    }
+
+    #[test]
+    fn slice_as_ptr_array() {
+        let strings = [NSString::from_str("a"), NSString::from_str("b")];
+        let ptrs = Arc::slice_as_ptr_array(&strings);
+        assert_eq!(ptrs.len(), strings.len());
+        assert_eq!(ptrs[1], &*strings[1] as *const NSString);
+
+        // `from_slice` passes the pointer array to `initWithObjects:count:`.
+        let array = NSArray::from_slice(&strings);
+        assert_eq!(array.count(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "dispatch")]
+    fn send_arc() {
+        use crate::dispatch::{DispatchQosClass, DispatchQueue};
+        use std::sync::mpsc;
+
+        let number = SendArc::new(NSNumber::from_int(42));
+        let (sender, receiver) = mpsc::channel();
+
+        DispatchQueue::global_with_qos(DispatchQosClass::Default).spawn_async(move || {
+            sender.send(number.to_string()).unwrap();
+        });
+
+        assert_eq!(receiver.recv().unwrap(), "42");
+    }
+
+    #[test]
+    fn upgrade() {
+        let obj = Arc::<NSObject>::default();
+        let weak = Arc::downgrade(&obj);
+        let clone = weak.clone();
+
+        let upgraded = weak.upgrade().unwrap();
+        assert!(Arc::ptr_eq(&upgraded, &obj));
+        assert_eq!(obj.retain_count(), 2);
+        drop(upgraded);
+
+        drop(obj);
+        assert!(weak.upgrade().is_none());
+        assert!(clone.upgrade().is_none());
+
+        assert!(Weak::<NSObject>::new().upgrade().is_none());
+    }
 }
@@ -1,5 +1,6 @@
 use std::{
     ascii,
+    cmp::Ordering,
     fmt::{self, Write},
 };
 
@@ -19,10 +20,37 @@ use std::{
 ///
 /// const APPL: FourCharCode = FourCharCode::from_chars(*b"APPL");
 /// ```
+///
+/// # Ordering
+///
+/// Codes are ordered lexically by their characters, regardless of the
+/// endianness of the target. Because [`from_int`](Self::from_int) interprets
+/// its input as big-endian characters, this is also the order of
+/// [`into_int`](Self::into_int) values.
+///
+/// ```
+/// use fruity::core::FourCharCode;
+///
+/// assert!(FourCharCode::from_chars(*b"AAAA") < FourCharCode::from_chars(*b"AAAB"));
+/// ```
 #[repr(transparent)]
-#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct FourCharCode(u32);
 
+impl PartialOrd for FourCharCode {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FourCharCode {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.into_chars().cmp(&other.into_chars())
+    }
+}
+
 impl fmt::Debug for FourCharCode {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // Format as escaped ASCII string.
@@ -84,3 +112,22 @@ impl FourCharCode {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ord_by_chars() {
+        let aaaa = FourCharCode::from_chars(*b"AAAA");
+        let aaab = FourCharCode::from_chars(*b"AAAB");
+        let baaa = FourCharCode::from_chars(*b"BAAA");
+
+        assert!(aaaa < aaab);
+        assert!(aaab < baaa);
+
+        let mut codes = [baaa, aaab, aaaa];
+        codes.sort();
+        assert_eq!(codes, [aaaa, aaab, baaa]);
+    }
+}
@@ -1,5 +1,10 @@
 use super::OSErr;
-use std::num::NonZeroI32;
+use std::{
+    ffi::CStr,
+    fmt,
+    num::NonZeroI32,
+    os::raw::{c_char, c_int},
+};
 
 /// A non-zero 32-bit error code.
 ///
@@ -18,6 +23,16 @@ use std::num::NonZeroI32;
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct OSStatus(NonZeroI32);
 
+impl fmt::Display for OSStatus {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.message() {
+            Some(message) => write!(f, "{} ({})", message, self.value()),
+            None => write!(f, "OSStatus {}", self.value()),
+        }
+    }
+}
+
 impl From<OSErr> for OSStatus {
     #[inline]
     fn from(error: OSErr) -> Self {
@@ -77,3 +92,90 @@ impl OSStatus {
         self.0
     }
 }
+
+/// Describing errors.
+impl OSStatus {
+    /// Returns a human-readable description of this error, if one is known.
+    ///
+    /// Positive values are first looked up as Mach errors with
+    /// [`mach_error_string`](https://developer.apple.com/documentation/kernel/1537750-mach_error_string).
+    /// Otherwise, if the **`core_services`** feature is enabled, the name of
+    /// the error is looked up with
+    /// [`GetMacOSStatusErrorString`](https://developer.apple.com/documentation/coreservices/1557942-getmacosstatuserrorstring).
+    #[doc(alias = "mach_error_string")]
+    #[doc(alias = "GetMacOSStatusErrorString")]
+    pub fn message(&self) -> Option<String> {
+        let value = self.value();
+
+        if value > 0 {
+            if let Some(message) = Self::mach_error_message(value) {
+                return Some(message);
+            }
+        }
+
+        Self::core_services_message(value)
+    }
+
+    fn mach_error_message(value: i32) -> Option<String> {
+        extern "C" {
+            fn mach_error_string(error_value: c_int) -> *const c_char;
+        }
+
+        let message = unsafe { c_str_to_string(mach_error_string(value))? };
+
+        // Unrecognized values are described rather than rejected.
+        if message.ends_with("unknown error code") {
+            None
+        } else {
+            Some(message)
+        }
+    }
+
+    #[cfg(feature = "core_services")]
+    fn core_services_message(value: i32) -> Option<String> {
+        use crate::core_services::sys::GetMacOSStatusErrorString;
+
+        unsafe { c_str_to_string(GetMacOSStatusErrorString(value)) }
+    }
+
+    #[cfg(not(feature = "core_services"))]
+    fn core_services_message(_value: i32) -> Option<String> {
+        None
+    }
+}
+
+/// Copies a C string, returning `None` if it is null or empty.
+unsafe fn c_str_to_string(ptr: *const c_char) -> Option<String> {
+    if ptr.is_null() {
+        return None;
+    }
+    let message = CStr::from_ptr(ptr).to_string_lossy();
+    if message.is_empty() {
+        None
+    } else {
+        Some(message.into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mach_message() {
+        // `KERN_INVALID_ADDRESS`
+        let status = OSStatus::new(1).unwrap();
+        let message = status.message().unwrap();
+        assert!(message.contains("invalid address"), "{}", message);
+        assert_eq!(status.to_string(), format!("{} (1)", message));
+    }
+
+    #[test]
+    #[cfg(feature = "core_services")]
+    fn core_services_message() {
+        // `fnfErr`
+        let status = OSStatus::new(-43).unwrap();
+        let message = status.message().unwrap();
+        assert!(!message.is_empty());
+    }
+}
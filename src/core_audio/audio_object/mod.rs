@@ -0,0 +1,173 @@
+use super::sys;
+use crate::core::{FourCharCode, OSStatus};
+use std::{ffi::c_void, ptr};
+
+/// Identifies an audio object, such as a device or stream, within the audio
+/// hardware abstraction layer.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreaudio/audioobjectid?language=objc).
+pub type AudioObjectID = u32;
+
+/// Returns the identifier of the singleton object that represents the audio
+/// hardware of the system.
+///
+/// Properties of this object include the list of devices and the default
+/// input and output devices.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreaudio/kaudioobjectsystemobject?language=objc).
+#[inline]
+#[doc(alias = "kAudioObjectSystemObject")]
+pub const fn system_object() -> AudioObjectID {
+    1
+}
+
+/// Identifies a property of an [audio object](AudioObjectID).
+///
+/// See [documentation](https://developer.apple.com/documentation/coreaudio/audioobjectpropertyaddress?language=objc).
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct AudioObjectPropertyAddress {
+    /// The property being queried, such as
+    /// [`HARDWARE_PROPERTY_DEFAULT_OUTPUT_DEVICE`].
+    #[doc(alias = "mSelector")]
+    pub selector: FourCharCode,
+
+    /// The scope of the property, such as [`PROPERTY_SCOPE_GLOBAL`].
+    #[doc(alias = "mScope")]
+    pub scope: FourCharCode,
+
+    /// The element of the property, such as [`PROPERTY_ELEMENT_MAIN`].
+    #[doc(alias = "mElement")]
+    pub element: FourCharCode,
+}
+
+impl AudioObjectPropertyAddress {
+    /// Creates an address for `selector` in the global scope of the main
+    /// element.
+    #[inline]
+    pub const fn global(selector: FourCharCode) -> Self {
+        Self {
+            selector,
+            scope: PROPERTY_SCOPE_GLOBAL,
+            element: PROPERTY_ELEMENT_MAIN,
+        }
+    }
+}
+
+/// The scope of properties that apply to an object as a whole.
+///
+/// Value: `glob`.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreaudio/kaudioobjectpropertyscopeglobal?language=objc).
+#[doc(alias = "kAudioObjectPropertyScopeGlobal")]
+pub const PROPERTY_SCOPE_GLOBAL: FourCharCode = FourCharCode::from_chars(*b"glob");
+
+/// The main element of an object.
+///
+/// Value: 0.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreaudio/kaudioobjectpropertyelementmain?language=objc).
+#[doc(alias = "kAudioObjectPropertyElementMain")]
+#[doc(alias = "kAudioObjectPropertyElementMaster")]
+pub const PROPERTY_ELEMENT_MAIN: FourCharCode = FourCharCode::from_int(0);
+
+/// The [`AudioObjectID`]s of all audio devices of the
+/// [system object](system_object).
+///
+/// Value: `dev#`.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreaudio/kaudiohardwarepropertydevices?language=objc).
+#[doc(alias = "kAudioHardwarePropertyDevices")]
+pub const HARDWARE_PROPERTY_DEVICES: FourCharCode = FourCharCode::from_chars(*b"dev#");
+
+/// The [`AudioObjectID`] of the default input device of the
+/// [system object](system_object).
+///
+/// Value: `dIn `.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreaudio/kaudiohardwarepropertydefaultinputdevice?language=objc).
+#[doc(alias = "kAudioHardwarePropertyDefaultInputDevice")]
+pub const HARDWARE_PROPERTY_DEFAULT_INPUT_DEVICE: FourCharCode = FourCharCode::from_chars(*b"dIn ");
+
+/// The [`AudioObjectID`] of the default output device of the
+/// [system object](system_object).
+///
+/// Value: `dOut`.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreaudio/kaudiohardwarepropertydefaultoutputdevice?language=objc).
+#[doc(alias = "kAudioHardwarePropertyDefaultOutputDevice")]
+pub const HARDWARE_PROPERTY_DEFAULT_OUTPUT_DEVICE: FourCharCode =
+    FourCharCode::from_chars(*b"dOut");
+
+/// Returns the value of the property at `address` of the object `id`.
+///
+/// Some properties take `qualifier` data that refines the query; pass an empty
+/// slice otherwise.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreaudio/1422524-audioobjectgetpropertydata?language=objc).
+#[doc(alias = "AudioObjectGetPropertyData")]
+#[doc(alias = "AudioObjectGetPropertyDataSize")]
+pub fn get_property_data(
+    id: AudioObjectID,
+    address: &AudioObjectPropertyAddress,
+    qualifier: &[u8],
+) -> Result<Vec<u8>, OSStatus> {
+    let qualifier_size = qualifier.len() as u32;
+    let qualifier = if qualifier.is_empty() {
+        ptr::null()
+    } else {
+        qualifier.as_ptr().cast::<c_void>()
+    };
+
+    let mut size: u32 = 0;
+    if let Some(error) = unsafe {
+        sys::AudioObjectGetPropertyDataSize(id, address, qualifier_size, qualifier, &mut size)
+    } {
+        return Err(error);
+    }
+
+    let mut data = Vec::<u8>::with_capacity(size as usize);
+    if let Some(error) = unsafe {
+        sys::AudioObjectGetPropertyData(
+            id,
+            address,
+            qualifier_size,
+            qualifier,
+            &mut size,
+            data.as_mut_ptr().cast(),
+        )
+    } {
+        return Err(error);
+    }
+
+    // SAFETY: `size` has been updated to the number of bytes written, which
+    // never exceeds the capacity that was passed in.
+    unsafe { data.set_len(size as usize) };
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryInto;
+
+    #[test]
+    #[ignore = "requires audio hardware"]
+    fn default_output_device() {
+        let address = AudioObjectPropertyAddress::global(HARDWARE_PROPERTY_DEFAULT_OUTPUT_DEVICE);
+        let data = get_property_data(system_object(), &address, &[]).unwrap();
+
+        let device = AudioObjectID::from_ne_bytes(data[..].try_into().unwrap());
+        assert_ne!(device, 0, "no default output device");
+
+        let address = AudioObjectPropertyAddress::global(HARDWARE_PROPERTY_DEVICES);
+        let data = get_property_data(system_object(), &address, &[]).unwrap();
+
+        let devices: Vec<AudioObjectID> = data
+            .chunks_exact(4)
+            .map(|chunk| AudioObjectID::from_ne_bytes(chunk.try_into().unwrap()))
+            .collect();
+        assert!(devices.contains(&device));
+    }
+}
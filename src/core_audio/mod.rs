@@ -8,5 +8,8 @@
 
 #![cfg(feature = "core_audio")]
 
-#[link(name = "CoreAudio", kind = "framework")]
-extern "C" {}
+pub mod sys;
+
+mod audio_object;
+
+pub use audio_object::*;
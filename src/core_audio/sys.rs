@@ -0,0 +1,28 @@
+//! Raw unsafe C functions exposed by `CoreAudio.framework`.
+
+use super::{AudioObjectID, AudioObjectPropertyAddress};
+use crate::core::OSStatus;
+use std::ffi::c_void;
+
+#[link(name = "CoreAudio", kind = "framework")]
+extern "C" {}
+
+#[allow(missing_docs)]
+extern "C" {
+    pub fn AudioObjectGetPropertyDataSize(
+        object_id: AudioObjectID,
+        address: *const AudioObjectPropertyAddress,
+        qualifier_data_size: u32,
+        qualifier_data: *const c_void,
+        out_data_size: *mut u32,
+    ) -> Option<OSStatus>;
+
+    pub fn AudioObjectGetPropertyData(
+        object_id: AudioObjectID,
+        address: *const AudioObjectPropertyAddress,
+        qualifier_data_size: u32,
+        qualifier_data: *const c_void,
+        io_data_size: *mut u32,
+        out_data: *mut c_void,
+    ) -> Option<OSStatus>;
+}
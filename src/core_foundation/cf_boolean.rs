@@ -1,4 +1,5 @@
 use super::{sys, CFType, CFTypeID};
+use crate::core::Arc;
 use std::{cmp::Ordering, fmt};
 
 subclass! {
@@ -15,7 +16,7 @@ subclass! {
 #[cfg(feature = "foundation")]
 mod foundation_casts {
     use super::*;
-    use crate::{core::Arc, foundation::NSNumber};
+    use crate::foundation::NSNumber;
 
     impl From<Arc<CFBoolean>> for Arc<NSNumber> {
         #[inline]
@@ -62,6 +63,27 @@ impl Ord for CFBoolean {
     }
 }
 
+impl From<bool> for &'static CFBoolean {
+    #[inline]
+    fn from(value: bool) -> Self {
+        CFBoolean::new(value)
+    }
+}
+
+impl From<bool> for Arc<CFBoolean> {
+    #[inline]
+    fn from(value: bool) -> Self {
+        Arc::retain(CFBoolean::new(value))
+    }
+}
+
+impl From<&CFBoolean> for bool {
+    #[inline]
+    fn from(boolean: &CFBoolean) -> Self {
+        boolean.as_bool()
+    }
+}
+
 impl Default for &CFBoolean {
     #[inline]
     fn default() -> Self {
@@ -127,3 +149,26 @@ impl CFBoolean {
         unsafe { sys::CFBooleanGetValue(self) != 0 }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_bool() {
+        for value in [false, true] {
+            let boolean = <&CFBoolean>::from(value);
+            assert_eq!(boolean.as_bool(), value);
+            assert_eq!(bool::from(boolean), value);
+
+            let boolean = Arc::<CFBoolean>::from(value);
+            assert_eq!(boolean.as_bool(), value);
+        }
+
+        assert!(std::ptr::eq(
+            <&CFBoolean>::from(true),
+            CFBoolean::true_value()
+        ));
+        assert!(<&CFBoolean>::default() < CFBoolean::true_value());
+    }
+}
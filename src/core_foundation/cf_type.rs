@@ -75,6 +75,23 @@ impl fmt::Debug for CFType<'_> {
 }
 
 impl<'data> CFType<'data> {
+    /// Retains this object, returning an owned reference to it.
+    ///
+    /// This is useful for taking ownership of an object obtained under the
+    /// [Get Rule], which does not transfer ownership to the caller. Objects
+    /// obtained under the [Create Rule] are already owned and can instead be
+    /// wrapped directly with [`Arc::from_raw`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/1521269-cfretain).
+    ///
+    /// [Get Rule]: https://developer.apple.com/library/archive/documentation/CoreFoundation/Conceptual/CFMemoryMgmt/Concepts/Ownership.html#//apple_ref/doc/uid/20001148-SW1
+    /// [Create Rule]: https://developer.apple.com/library/archive/documentation/CoreFoundation/Conceptual/CFMemoryMgmt/Concepts/Ownership.html#//apple_ref/doc/uid/20001148-103029
+    #[inline]
+    #[doc(alias = "CFRetain")]
+    pub fn retain(&self) -> Arc<Self> {
+        ObjectType::retain(self)
+    }
+
     /// Returns this object's reference count.
     ///
     /// This method is only useful for debugging certain objects.
@@ -106,6 +123,28 @@ impl<'data> CFType<'data> {
     // TODO: `CFCopyDescription`
 }
 
+/// Releases a Core Foundation object.
+///
+/// This balances a retain performed outside of [`Arc`], such as an object
+/// obtained under the [Create Rule] or passed to [`CFRetain`]. Objects obtained
+/// under the [Get Rule] are not owned by the caller and must not be released.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/1521153-cfrelease).
+///
+/// # Safety
+///
+/// `obj` must be a valid pointer to an object that the caller owns a
+/// reference to. That reference must not be used after this call.
+///
+/// [`CFRetain`]: https://developer.apple.com/documentation/corefoundation/1521269-cfretain
+/// [Get Rule]: https://developer.apple.com/library/archive/documentation/CoreFoundation/Conceptual/CFMemoryMgmt/Concepts/Ownership.html#//apple_ref/doc/uid/20001148-SW1
+/// [Create Rule]: https://developer.apple.com/library/archive/documentation/CoreFoundation/Conceptual/CFMemoryMgmt/Concepts/Ownership.html#//apple_ref/doc/uid/20001148-103029
+#[inline]
+#[doc(alias = "CFRelease")]
+pub unsafe fn cf_release(obj: *const CFType) {
+    sys::CFRelease(obj);
+}
+
 /// An automatically-reference-counted pointer to a type-erased Core Foundation
 /// object.
 ///
@@ -113,3 +152,29 @@ impl<'data> CFType<'data> {
 /// [Swift](https://developer.apple.com/documentation/corefoundation/cftyperef?language=swift) |
 /// [Objective-C](https://developer.apple.com/documentation/corefoundation/cftyperef?language=objc)
 pub type CFTypeRef<'data> = Arc<CFType<'data>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_foundation::CFNumber;
+
+    #[test]
+    fn retain_release() {
+        // Large values are not stored in tagged pointers.
+        let number = CFNumber::new(i64::MAX);
+        let obj: &CFType = &number;
+        let count = obj.retain_count();
+
+        let retained = obj.retain();
+        assert_eq!(obj.retain_count(), count + 1);
+
+        drop(retained);
+        assert_eq!(obj.retain_count(), count);
+
+        let raw = Arc::into_raw(obj.retain());
+        assert_eq!(obj.retain_count(), count + 1);
+
+        unsafe { cf_release(raw) };
+        assert_eq!(obj.retain_count(), count);
+    }
+}
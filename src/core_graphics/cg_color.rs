@@ -0,0 +1,60 @@
+use super::{CGColorSpace, CGFloat};
+use crate::core::Arc;
+use crate::core_foundation::CFType;
+
+subclass! {
+    /// A set of components that define a color, with a color space specifying
+    /// how to interpret them.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolor).
+    pub class CGColor: CFType<'static>;
+}
+
+impl CGColor {
+    /// Creates a color using a list of intensity values (including alpha) and
+    /// an associated color space.
+    ///
+    /// The number of components must be one more than the number of components
+    /// of `space`, or else `None` is returned.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/1455631-cgcolorcreate).
+    #[inline]
+    #[doc(alias = "CGColorCreate")]
+    pub fn new(space: &CGColorSpace, components: &[CGFloat]) -> Option<Arc<Self>> {
+        extern "C" {
+            fn CGColorCreate(
+                space: &CGColorSpace,
+                components: *const CGFloat,
+            ) -> Option<Arc<CGColor>>;
+        }
+
+        if components.len() != space.number_of_components() + 1 {
+            return None;
+        }
+        unsafe { CGColorCreate(space, components.as_ptr()) }
+    }
+
+    /// Returns the number of color components (including alpha) of this color.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/1455417-cgcolorgetnumberofcomponents).
+    #[inline]
+    #[doc(alias = "CGColorGetNumberOfComponents")]
+    pub fn number_of_components(&self) -> usize {
+        extern "C" {
+            fn CGColorGetNumberOfComponents(color: &CGColor) -> usize;
+        }
+        unsafe { CGColorGetNumberOfComponents(self) }
+    }
+
+    /// Returns the value of the alpha component of this color.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/1455543-cgcolorgetalpha).
+    #[inline]
+    #[doc(alias = "CGColorGetAlpha")]
+    pub fn alpha(&self) -> CGFloat {
+        extern "C" {
+            fn CGColorGetAlpha(color: &CGColor) -> CGFloat;
+        }
+        unsafe { CGColorGetAlpha(self) }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::core::Arc;
+use crate::core_foundation::CFType;
+
+subclass! {
+    /// A profile that specifies how to interpret a color value for display.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolorspace).
+    pub class CGColorSpace: CFType<'static>;
+}
+
+impl CGColorSpace {
+    /// Creates a device-dependent RGB color space.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/1408837-cgcolorspacecreatedevicergb).
+    #[inline]
+    #[doc(alias = "CGColorSpaceCreateDeviceRGB")]
+    pub fn device_rgb() -> Arc<Self> {
+        extern "C" {
+            fn CGColorSpaceCreateDeviceRGB() -> Arc<CGColorSpace>;
+        }
+        unsafe { CGColorSpaceCreateDeviceRGB() }
+    }
+
+    /// Creates a device-dependent grayscale color space.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/1408790-cgcolorspacecreatedevicegray).
+    #[inline]
+    #[doc(alias = "CGColorSpaceCreateDeviceGray")]
+    pub fn device_gray() -> Arc<Self> {
+        extern "C" {
+            fn CGColorSpaceCreateDeviceGray() -> Arc<CGColorSpace>;
+        }
+        unsafe { CGColorSpaceCreateDeviceGray() }
+    }
+
+    /// Returns the number of color components in this color space, excluding
+    /// alpha.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/1408883-cgcolorspacegetnumberofcomponent).
+    #[inline]
+    #[doc(alias = "CGColorSpaceGetNumberOfComponents")]
+    pub fn number_of_components(&self) -> usize {
+        extern "C" {
+            fn CGColorSpaceGetNumberOfComponents(space: &CGColorSpace) -> usize;
+        }
+        unsafe { CGColorSpaceGetNumberOfComponents(self) }
+    }
+}
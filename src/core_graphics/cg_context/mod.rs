@@ -0,0 +1,127 @@
+use super::{CGColor, CGColorSpace, CGRect};
+use crate::core::Arc;
+use crate::core_foundation::CFType;
+use std::{ffi::c_void, ptr};
+
+subclass! {
+    /// A Quartz 2D drawing destination.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcontext).
+    pub class CGContext: CFType<'static>;
+}
+
+/// Value of `kCGImageAlphaPremultipliedLast`.
+const IMAGE_ALPHA_PREMULTIPLIED_LAST: u32 = 1;
+
+/// Bitmap contexts.
+impl CGContext {
+    /// Creates a bitmap graphics context of `width` by `height` pixels with
+    /// its own backing buffer.
+    ///
+    /// Pixels are stored as 8-bit RGBA components with premultiplied alpha in
+    /// the device RGB color space. Use [`data`](Self::data) and
+    /// [`bytes_per_row`](Self::bytes_per_row) to access them.
+    ///
+    /// Returns `None` if the context could not be created.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/1455939-cgbitmapcontextcreate).
+    #[inline]
+    #[doc(alias = "CGBitmapContextCreate")]
+    pub fn bitmap_context(width: usize, height: usize) -> Option<Arc<Self>> {
+        extern "C" {
+            fn CGBitmapContextCreate(
+                data: *mut c_void,
+                width: usize,
+                height: usize,
+                bits_per_component: usize,
+                bytes_per_row: usize,
+                space: &CGColorSpace,
+                bitmap_info: u32,
+            ) -> Option<Arc<CGContext>>;
+        }
+
+        let space = CGColorSpace::device_rgb();
+        unsafe {
+            CGBitmapContextCreate(
+                ptr::null_mut(),
+                width,
+                height,
+                8,
+                // Calculated automatically.
+                0,
+                &space,
+                IMAGE_ALPHA_PREMULTIPLIED_LAST,
+            )
+        }
+    }
+
+    /// Returns a pointer to the image data of this bitmap context, or null if
+    /// this is not a bitmap context.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/1456390-cgbitmapcontextgetdata).
+    #[inline]
+    #[doc(alias = "CGBitmapContextGetData")]
+    pub fn data(&self) -> *mut c_void {
+        extern "C" {
+            fn CGBitmapContextGetData(context: &CGContext) -> *mut c_void;
+        }
+        unsafe { CGBitmapContextGetData(self) }
+    }
+
+    /// Returns the number of bytes in each row of this bitmap context, or 0 if
+    /// this is not a bitmap context.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/1455642-cgbitmapcontextgetbytesperrow).
+    #[inline]
+    #[doc(alias = "CGBitmapContextGetBytesPerRow")]
+    pub fn bytes_per_row(&self) -> usize {
+        extern "C" {
+            fn CGBitmapContextGetBytesPerRow(context: &CGContext) -> usize;
+        }
+        unsafe { CGBitmapContextGetBytesPerRow(self) }
+    }
+}
+
+/// Drawing.
+impl CGContext {
+    /// Sets the current fill color.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/1455471-cgcontextsetfillcolorwithcolor).
+    #[inline]
+    #[doc(alias = "CGContextSetFillColorWithColor")]
+    pub fn set_fill_color(&self, color: &CGColor) {
+        extern "C" {
+            fn CGContextSetFillColorWithColor(context: &CGContext, color: &CGColor);
+        }
+        unsafe { CGContextSetFillColorWithColor(self, color) }
+    }
+
+    /// Paints the area contained within `rect` using the current fill color.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/1454160-cgcontextfillrect).
+    #[inline]
+    #[doc(alias = "CGContextFillRect")]
+    pub fn fill_rect(&self, rect: CGRect) {
+        extern "C" {
+            fn CGContextFillRect(context: &CGContext, rect: CGRect);
+        }
+        unsafe { CGContextFillRect(self, rect) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill_bitmap() {
+        let context = CGContext::bitmap_context(2, 2).unwrap();
+
+        let red = CGColor::new(&CGColorSpace::device_rgb(), &[1.0, 0.0, 0.0, 1.0]).unwrap();
+        context.set_fill_color(&red);
+        context.fill_rect(CGRect::new(0.0, 0.0, 2.0, 2.0));
+
+        let pixel = unsafe { std::slice::from_raw_parts(context.data() as *const u8, 4) };
+        assert_eq!(pixel, [255, 0, 0, 255]);
+    }
+}
@@ -21,6 +21,9 @@ impl From<(CGFloat, CGFloat)> for CGPoint {
 
 impl CGPoint {
     /// A point at location `(0, 0)`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgpointzero).
+    #[doc(alias = "CGPointZero")]
     pub const ZERO: Self = Self::new(0.0, 0.0);
 
     /// Returns a point with the specified coordinates.
@@ -55,3 +58,25 @@ impl CGPoint {
         unsafe { CGPointApplyAffineTransform(self, transform) }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero() {
+        let expected = unsafe {
+            extern "C" {
+                static CGPointZero: CGPoint;
+            }
+            CGPointZero
+        };
+        assert_eq!(CGPoint::ZERO, expected);
+        assert_eq!(CGPoint::default(), expected);
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(CGPoint::new(1.0, 2.0), CGPoint { x: 1.0, y: 2.0 });
+    }
+}
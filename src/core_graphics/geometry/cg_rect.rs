@@ -475,6 +475,14 @@ mod tests {
             CGRectZero
         };
         assert_eq!(CGRect::ZERO, expected);
+        assert_eq!(CGRect::default(), expected);
+    }
+
+    #[test]
+    fn new() {
+        let rect = CGRect::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(rect.origin, CGPoint::new(1.0, 2.0));
+        assert_eq!(rect.size, CGSize::new(3.0, 4.0));
     }
 
     #[test]
@@ -21,6 +21,9 @@ impl From<(CGFloat, CGFloat)> for CGSize {
 
 impl CGSize {
     /// A size with a width and height of 0.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgsizezero).
+    #[doc(alias = "CGSizeZero")]
     pub const ZERO: Self = Self::new(0.0, 0.0);
 
     /// Returns a size with the specified dimension values.
@@ -51,3 +54,31 @@ impl CGSize {
         unsafe { CGSizeApplyAffineTransform(self, transform) }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero() {
+        let expected = unsafe {
+            extern "C" {
+                static CGSizeZero: CGSize;
+            }
+            CGSizeZero
+        };
+        assert_eq!(CGSize::ZERO, expected);
+        assert_eq!(CGSize::default(), expected);
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            CGSize::new(1.0, 2.0),
+            CGSize {
+                width: 1.0,
+                height: 2.0
+            }
+        );
+    }
+}
@@ -4,12 +4,20 @@
 //!
 //! This module corresponds to the **`core_graphics`**
 //! [feature flag](../index.html#feature-flags).
+//!
+//! It also transitively enables [`core_foundation`](crate::core_foundation).
 
 #![cfg(feature = "core_graphics")]
 
 #[link(name = "CoreGraphics", kind = "framework")]
 extern "C" {}
 
+mod cg_color;
+mod cg_color_space;
+mod cg_context;
 mod geometry;
 
+pub use cg_color::*;
+pub use cg_color_space::*;
+pub use cg_context::*;
 pub use geometry::*;
@@ -0,0 +1,90 @@
+use super::{CIFormat, CIImage};
+use crate::core::Arc;
+use crate::core_graphics::CGColorSpace;
+use crate::foundation::{NSData, NSDictionary, NSString};
+use crate::objc::{ClassType, NSObject, ObjCObject};
+
+objc_subclass! {
+    /// An evaluation context for rendering image processing results.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/cicontext).
+    pub class CIContext: NSObject<'static>;
+}
+
+impl Default for Arc<CIContext> {
+    #[inline]
+    fn default() -> Self {
+        CIContext::new()
+    }
+}
+
+impl CIContext {
+    /// Creates a context with the default options.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/cicontext/1437718-init).
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        Self::alloc().init()
+    }
+
+    /// Renders `image` into PNG data, or returns `None` if rendering fails.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/cicontext/2866191-pngrepresentationofimage).
+    #[inline]
+    #[doc(alias = "PNGRepresentationOfImage")]
+    #[doc(alias = "PNGRepresentationOfImage:format:colorSpace:options:")]
+    pub fn png_representation(
+        &self,
+        image: &CIImage,
+        format: CIFormat,
+        color_space: &CGColorSpace,
+        options: &NSDictionary<'static, NSString<'static>, ObjCObject<'static>>,
+    ) -> Option<Arc<NSData>> {
+        let data: Option<&NSData> = unsafe {
+            _msg_send_any![
+                self,
+                PNGRepresentationOfImage: image
+                format: format.0
+                colorSpace: color_space
+                options: options
+            ]
+        };
+        data.map(Arc::retain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_graphics::CGRect;
+
+    // A 3x2 opaque red image.
+    const PNG: &[u8] = &[
+        0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x48, 0x44,
+        0x52, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x02, 0x08, 0x02, 0x00, 0x00, 0x00, 0x12,
+        0x16, 0xF1, 0x4D, 0x00, 0x00, 0x00, 0x10, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9C, 0x63, 0xF8,
+        0xCF, 0xC0, 0x00, 0x41, 0x0C, 0x70, 0x16, 0x00, 0x41, 0xD2, 0x05, 0xFB, 0x87, 0xF0, 0xB9,
+        0x48, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4E, 0x44, 0xAE, 0x42, 0x60, 0x82,
+    ];
+
+    #[test]
+    fn png_round_trip() {
+        let image = CIImage::from_data(&NSData::from_bytes(PNG)).unwrap();
+        assert_eq!(image.extent(), CGRect::new(0.0, 0.0, 3.0, 2.0));
+
+        let options = NSDictionary::from_keys_and_objects(&[], &[]);
+        let png = CIContext::new()
+            .png_representation(
+                &image,
+                CIFormat::rgba8(),
+                &CGColorSpace::device_rgb(),
+                &options,
+            )
+            .unwrap();
+
+        let decoded = CIImage::from_data(&png).unwrap();
+        assert_eq!(decoded.extent(), image.extent());
+
+        assert!(CIImage::from_data(&NSData::from_bytes(b"fruity")).is_none());
+    }
+}
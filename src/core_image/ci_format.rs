@@ -0,0 +1,23 @@
+use std::os::raw::c_int;
+
+/// A pixel format for image data.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreimage/ciformat).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CIFormat(pub c_int);
+
+impl CIFormat {
+    /// A 32-bit-per-pixel, fixed-point pixel format in which the red, green,
+    /// blue, and alpha components are stored in that order.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/kciformatrgba8).
+    #[inline]
+    #[doc(alias = "kCIFormatRGBA8")]
+    pub fn rgba8() -> Self {
+        extern "C" {
+            static kCIFormatRGBA8: CIFormat;
+        }
+        unsafe { kCIFormatRGBA8 }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::core::Arc;
+use crate::core_graphics::CGRect;
+use crate::foundation::NSData;
+use crate::objc::{ClassType, NSObject};
+
+objc_subclass! {
+    /// A representation of an image to be processed or produced by Core Image
+    /// filters.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/ciimage).
+    pub class CIImage: NSObject<'static>;
+}
+
+impl CIImage {
+    /// Creates an image by decoding `data`, or returns `None` if `data` is
+    /// not in a supported image format.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/ciimage/1547031-imagewithdata).
+    #[inline]
+    #[doc(alias = "imageWithData")]
+    #[doc(alias = "imageWithData:")]
+    pub fn from_data(data: &NSData) -> Option<Arc<Self>> {
+        let image: Option<&Self> = unsafe { _msg_send_any![Self::class(), imageWithData: data] };
+        image.map(Arc::retain)
+    }
+
+    /// Returns the rectangle that specifies the extent of the image.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/ciimage/1437884-extent).
+    #[inline]
+    pub fn extent(&self) -> CGRect {
+        unsafe { _msg_send_any![self, extent] }
+    }
+}
@@ -8,5 +8,13 @@
 
 #![cfg(feature = "core_image")]
 
+mod ci_context;
+mod ci_format;
+mod ci_image;
+
+pub use ci_context::*;
+pub use ci_format::*;
+pub use ci_image::*;
+
 #[link(name = "CoreImage", kind = "framework")]
 extern "C" {}
@@ -1,4 +1,11 @@
 //! Raw unsafe C functions exposed by `CoreServices.framework`.
 
+use std::os::raw::c_char;
+
 #[link(name = "CoreServices", kind = "framework")]
 extern "C" {}
+
+#[allow(missing_docs)]
+extern "C" {
+    pub fn GetMacOSStatusErrorString(err: i32) -> *const c_char;
+}
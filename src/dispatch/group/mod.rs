@@ -0,0 +1,416 @@
+use super::{queue::DispatchFn, sys, DispatchObject, DispatchQueue, DispatchTime};
+use crate::core::{Arc, ThreadSafe};
+use std::{
+    ffi::c_void,
+    mem::{self, ManuallyDrop, MaybeUninit},
+    panic, process,
+};
+
+subclass! {
+    /// A group of tasks that are monitored as a single unit.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/dispatch/dispatchgroup) |
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/dispatch_group)
+    pub class DispatchGroup: DispatchObject;
+}
+
+// SAFETY: Dispatch objects are designed to be used from any thread.
+unsafe impl ThreadSafe for DispatchGroup {}
+
+impl DispatchGroup {
+    /// Creates a new group with which tasks can be associated.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/dispatch/dispatchgroup/1452747-init) |
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/1452771-dispatch_group_create?language=objc)
+    #[inline]
+    #[doc(alias = "dispatch_group_create")]
+    pub fn new() -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::dispatch_group_create()) }
+    }
+
+    /// Explicitly indicates that a task has entered the group.
+    ///
+    /// Each call must be balanced with a call to [`leave`](Self::leave).
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/dispatch/dispatchgroup/1452803-enter) |
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/1452803-dispatch_group_enter?language=objc)
+    #[inline]
+    #[doc(alias = "dispatch_group_enter")]
+    pub fn enter(&self) {
+        unsafe { sys::dispatch_group_enter(self) };
+    }
+
+    /// Explicitly indicates that a task in the group finished executing.
+    ///
+    /// Calling this more times than [`enter`](Self::enter) is a programmer
+    /// error and crashes the process.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/dispatch/dispatchgroup/1452872-leave) |
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/1452872-dispatch_group_leave?language=objc)
+    #[inline]
+    #[doc(alias = "dispatch_group_leave")]
+    pub fn leave(&self) {
+        unsafe { sys::dispatch_group_leave(self) };
+    }
+
+    /// Waits synchronously for all tasks in the group to finish executing.
+    ///
+    /// Returns `true` if all tasks finished, or `false` if `timeout` elapsed
+    /// first.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/dispatch/dispatchgroup/2016090-wait) |
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/1452794-dispatch_group_wait?language=objc)
+    #[inline]
+    #[doc(alias = "dispatch_group_wait")]
+    pub fn wait(&self, timeout: DispatchTime) -> bool {
+        unsafe { sys::dispatch_group_wait(self, timeout) == 0 }
+    }
+
+    /// Schedules `work` to be submitted to `queue` once all tasks currently in
+    /// the group have finished executing.
+    ///
+    /// If the group is empty, `work` is submitted immediately.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/dispatch/dispatchgroup/2016066-notify) |
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/1452876-dispatch_group_notify_f?language=objc)
+    ///
+    /// # Safety
+    ///
+    /// It is safe to panic within the `work` function. Panics will abort the
+    /// process.
+    ///
+    /// If the overhead of the extra setup is undesirable or you would like to
+    /// handle panics yourself, use [`notify_no_panic`](Self::notify_no_panic)
+    /// or [`notify_raw`](Self::notify_raw) instead.
+    #[inline]
+    #[doc(alias = "dispatch_group_notify")]
+    #[doc(alias = "dispatch_group_notify_f")]
+    pub fn notify<F>(&self, queue: &DispatchQueue, work: F)
+    where
+        F: Send + FnOnce() + 'static,
+    {
+        // Wrap `work` to abort on panic.
+        let work = || match panic::catch_unwind(panic::AssertUnwindSafe(work)) {
+            Ok(()) => {}
+            Err(_error) => process::abort(),
+        };
+
+        // SAFETY: Any panics within `work` are caught.
+        unsafe { self.notify_no_panic(queue, work) };
+    }
+
+    /// Schedules `work` to be submitted to `queue` once all tasks currently in
+    /// the group have finished executing, without catching panics.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/dispatch/dispatchgroup/2016066-notify) |
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/1452876-dispatch_group_notify_f?language=objc)
+    ///
+    /// # Safety
+    ///
+    /// It is undefined behavior to panic within the `work` function because it
+    /// is called from an `extern "C" fn`. Catch the panic yourself or call
+    /// [`notify`](Self::notify) instead.
+    #[inline]
+    #[doc(alias = "dispatch_group_notify")]
+    #[doc(alias = "dispatch_group_notify_f")]
+    pub unsafe fn notify_no_panic<F>(&self, queue: &DispatchQueue, work: F)
+    where
+        F: Send + FnOnce() + 'static,
+    {
+        extern "C" fn wrapped_work<F>(ctx: *mut F)
+        where
+            F: Send + FnOnce() + 'static,
+        {
+            // SAFETY: `work` is only used from within this function.
+            let work = unsafe { Box::from_raw(ctx) };
+
+            work();
+        }
+
+        self.notify_raw(queue, Box::into_raw(Box::new(work)), wrapped_work);
+    }
+
+    /// Schedules a C function with a context pointer to be submitted to
+    /// `queue` once all tasks currently in the group have finished executing.
+    ///
+    /// Documentation:
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/1452876-dispatch_group_notify_f?language=objc)
+    #[inline]
+    #[doc(alias = "dispatch_group_notify")]
+    #[doc(alias = "dispatch_group_notify_f")]
+    pub fn notify_raw<Ctx>(
+        &self,
+        queue: &DispatchQueue,
+        ctx: *mut Ctx,
+        work: extern "C" fn(*mut Ctx),
+    ) {
+        unsafe {
+            // SAFETY: Both functions have the same ABI.
+            let work: DispatchFn = mem::transmute(work);
+
+            // SAFETY: The group, queue, and `work` are non-null, which is
+            // required by this function.
+            //
+            // And `work` is not an `unsafe fn`, so it needs to handle safety
+            // internally.
+            sys::dispatch_group_notify_f(self, queue, ctx.cast(), work);
+        }
+    }
+
+    /// Submits `work` to `queue` for asynchronous execution and associates it
+    /// with the group.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/dispatch/dispatchqueue/2016098-async) |
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/1452827-dispatch_group_async_f?language=objc)
+    ///
+    /// # Safety
+    ///
+    /// It is safe to panic within the `work` function. Panics will abort the
+    /// process.
+    ///
+    /// If the overhead of the extra setup is undesirable or you would like to
+    /// handle panics yourself, use
+    /// [`async_work_no_panic`](Self::async_work_no_panic) or
+    /// [`async_work_raw`](Self::async_work_raw) instead.
+    #[inline]
+    #[doc(alias = "dispatch_group_async")]
+    #[doc(alias = "dispatch_group_async_f")]
+    pub fn async_work<F>(&self, queue: &DispatchQueue, work: F)
+    where
+        F: Send + FnOnce() + 'static,
+    {
+        // Wrap `work` to abort on panic.
+        let work = || match panic::catch_unwind(panic::AssertUnwindSafe(work)) {
+            Ok(()) => {}
+            Err(_error) => process::abort(),
+        };
+
+        // SAFETY: Any panics within `work` are caught.
+        unsafe { self.async_work_no_panic(queue, work) };
+    }
+
+    /// Submits `work` to `queue` for asynchronous execution and associates it
+    /// with the group, without catching panics.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/dispatch/dispatchqueue/2016098-async) |
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/1452827-dispatch_group_async_f?language=objc)
+    ///
+    /// # Safety
+    ///
+    /// It is undefined behavior to panic within the `work` function because it
+    /// is called from an `extern "C" fn`. Catch the panic yourself or call
+    /// [`async_work`](Self::async_work) instead.
+    #[inline]
+    #[doc(alias = "dispatch_group_async")]
+    #[doc(alias = "dispatch_group_async_f")]
+    pub unsafe fn async_work_no_panic<F>(&self, queue: &DispatchQueue, work: F)
+    where
+        F: Send + FnOnce() + 'static,
+    {
+        extern "C" fn wrapped_work<F>(ctx: *mut F)
+        where
+            F: Send + FnOnce() + 'static,
+        {
+            // SAFETY: `work` is only used from within this function.
+            let work = unsafe { Box::from_raw(ctx) };
+
+            work();
+        }
+
+        self.async_work_raw(queue, Box::into_raw(Box::new(work)), wrapped_work);
+    }
+
+    /// Submits a C function with a context pointer to `queue` for asynchronous
+    /// execution and associates it with the group.
+    ///
+    /// Documentation:
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/1452827-dispatch_group_async_f?language=objc)
+    #[inline]
+    #[doc(alias = "dispatch_group_async")]
+    #[doc(alias = "dispatch_group_async_f")]
+    pub fn async_work_raw<Ctx>(
+        &self,
+        queue: &DispatchQueue,
+        ctx: *mut Ctx,
+        work: extern "C" fn(*mut Ctx),
+    ) {
+        unsafe {
+            // SAFETY: Both functions have the same ABI.
+            let work: DispatchFn = mem::transmute(work);
+
+            // SAFETY: The group, queue, and `work` are non-null, which is
+            // required by this function.
+            //
+            // And `work` is not an `unsafe fn`, so it needs to handle safety
+            // internally.
+            sys::dispatch_group_async_f(self, queue, ctx.cast(), work);
+        }
+    }
+
+    /// Submits each of `tasks` to `queue` for asynchronous execution within
+    /// the group, waits for the group to finish, and returns the results of
+    /// `tasks` in order.
+    ///
+    /// Because this waits for the entire group, it also waits for any other
+    /// tasks associated with the group. Calling this from a task running on a
+    /// serial `queue` deadlocks.
+    ///
+    /// # Safety
+    ///
+    /// It is safe to panic within the `tasks` functions. Panics will abort the
+    /// process.
+    pub fn async_collect<T, F>(&self, queue: &DispatchQueue, tasks: Vec<F>) -> Vec<T>
+    where
+        T: Send,
+        F: Send + FnOnce() -> T,
+    {
+        // A task along with the slot to which its result is written.
+        struct Task<F, T> {
+            work: F,
+            slot: *mut MaybeUninit<T>,
+        }
+
+        unsafe extern "C" fn run_task<F, T>(ctx: *mut c_void)
+        where
+            F: FnOnce() -> T,
+        {
+            // SAFETY: Each task is only used from within this function.
+            let task = Box::from_raw(ctx.cast::<Task<F, T>>());
+            let Task { work, slot } = *task;
+
+            match panic::catch_unwind(panic::AssertUnwindSafe(work)) {
+                // SAFETY: Each task has its own slot, so no two writes alias.
+                Ok(value) => slot.write(MaybeUninit::new(value)),
+                Err(_error) => process::abort(),
+            }
+        }
+
+        let len = tasks.len();
+        let mut results = ManuallyDrop::new(Vec::<MaybeUninit<T>>::with_capacity(len));
+
+        // SAFETY: `MaybeUninit` does not require initialization.
+        unsafe { results.set_len(len) };
+
+        let slots = results.as_mut_ptr();
+
+        for (index, work) in tasks.into_iter().enumerate() {
+            let task = Box::new(Task {
+                work,
+                // SAFETY: `index` is within `0..len`.
+                slot: unsafe { slots.add(index) },
+            });
+
+            // SAFETY: `F` and `T` are `Send`, so running the task on another
+            // thread is equivalent to sending it there and sending the result
+            // back. Borrows within `F` outlive the task because this function
+            // waits for the group before returning.
+            unsafe {
+                sys::dispatch_group_async_f(
+                    self,
+                    queue,
+                    Box::into_raw(task).cast(),
+                    run_task::<F, T>,
+                );
+            }
+        }
+
+        self.wait(DispatchTime::DISTANT_FUTURE);
+
+        // SAFETY: Every task has completed, and panics abort the process
+        // rather than unwinding past this point. So every slot has been
+        // initialized exactly once.
+        //
+        // `MaybeUninit<T>` has the same layout as `T`, and `results` is never
+        // dropped, so ownership of the allocation is transferred.
+        unsafe { Vec::from_raw_parts(slots.cast::<T>(), results.len(), results.capacity()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            mpsc,
+        },
+        thread,
+        time::Duration,
+    };
+
+    #[test]
+    fn notify_after_tasks() {
+        const TASKS: usize = 8;
+
+        let group = DispatchGroup::new();
+        let queue = DispatchQueue::global();
+        let completed = std::sync::Arc::new(AtomicUsize::new(0));
+
+        for _ in 0..TASKS {
+            let completed = completed.clone();
+            group.async_work(queue, move || {
+                thread::sleep(Duration::from_millis(5));
+                completed.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        let notify_completed = completed.clone();
+        group.notify(queue, move || {
+            sender
+                .send(notify_completed.load(Ordering::SeqCst))
+                .unwrap();
+        });
+
+        let value = receiver.recv_timeout(Duration::from_secs(10));
+        assert_eq!(value, Ok(TASKS));
+
+        assert!(group.wait(DispatchTime::DISTANT_FUTURE));
+    }
+
+    #[test]
+    fn async_collect() {
+        let group = DispatchGroup::new();
+        let queue = DispatchQueue::global();
+
+        let prefix = String::from("task");
+        let tasks: Vec<_> = (0..16)
+            .map(|i| {
+                let prefix = &prefix;
+                move || {
+                    // Finish out of order.
+                    thread::sleep(Duration::from_millis(16 - i));
+                    format!("{}{}", prefix, i)
+                }
+            })
+            .collect();
+
+        let results = group.async_collect(queue, tasks);
+        let expected: Vec<String> = (0..16).map(|i| format!("task{}", i)).collect();
+        assert_eq!(results, expected);
+
+        let empty: Vec<fn() -> u8> = Vec::new();
+        assert!(group.async_collect(queue, empty).is_empty());
+    }
+
+    #[test]
+    fn wait_timeout() {
+        let group = DispatchGroup::new();
+        assert!(group.wait(DispatchTime::IMMEDIATE));
+
+        group.enter();
+        assert!(!group.wait(DispatchTime::from_millis_from_now(5)));
+
+        group.leave();
+        assert!(group.wait(DispatchTime::DISTANT_FUTURE));
+    }
+}
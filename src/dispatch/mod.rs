@@ -10,15 +10,61 @@
 pub mod sys;
 
 mod autorelease_frequency;
+mod group;
 mod object;
 mod qos;
 mod queue;
+mod semaphore;
 mod source;
 mod time;
 
 pub use autorelease_frequency::*;
+pub use group::*;
 pub use object::*;
 pub use qos::*;
 pub use queue::*;
+pub use semaphore::*;
 pub use source::*;
 pub use time::*;
+
+/// Executes work submitted to the [main queue](DispatchQueue::main), and never
+/// returns.
+///
+/// This is for programs, such as command-line tools, that have no other event
+/// loop running on the main thread. It must be called from the main thread,
+/// typically at the end of `main` after all initial work has been set up.
+/// Processes wishing to exit must do so explicitly, such as via
+/// [`std::process::exit`].
+///
+/// Applications that use an [`NSRunLoop`] or [`CFRunLoop`] on the main thread
+/// must not call this function.
+///
+/// Documentation:
+/// [Swift](https://developer.apple.com/documentation/dispatch/1452860-dispatchmain) |
+/// [Objective-C](https://developer.apple.com/documentation/dispatch/1452860-dispatch_main?language=objc)
+///
+/// [`NSRunLoop`]: https://developer.apple.com/documentation/foundation/nsrunloop
+/// [`CFRunLoop`]: https://developer.apple.com/documentation/corefoundation/cfrunloop
+#[inline]
+#[doc(alias = "dispatch_main")]
+pub fn main() -> ! {
+    unsafe { sys::dispatch_main() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{process, thread};
+
+    // `dispatch_main` takes over the calling thread and the block exits the
+    // process, so this must be run in isolation.
+    #[test]
+    #[ignore = "exits the test process"]
+    fn main_processes_main_queue() {
+        thread::spawn(|| {
+            DispatchQueue::main().spawn_async(|| process::exit(0));
+        });
+
+        main();
+    }
+}
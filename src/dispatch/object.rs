@@ -1,5 +1,5 @@
 use super::{sys, DispatchQueue};
-use crate::core::{Arc, ObjectType};
+use crate::core::{Arc, ObjectType, ThreadSafe};
 use std::{
     cell::UnsafeCell,
     ffi::c_void,
@@ -39,6 +39,9 @@ impl ObjectType for DispatchObject {
 unsafe impl Send for DispatchObject {}
 unsafe impl Sync for DispatchObject {}
 
+// SAFETY: Dispatch objects are designed to be used from any thread.
+unsafe impl ThreadSafe for DispatchObject {}
+
 // Although this uses `UnsafeCell`, it does not point to any Rust types.
 impl RefUnwindSafe for DispatchObject {}
 
@@ -131,4 +134,61 @@ impl DispatchObject {
     pub unsafe fn set_context(&self, context: *mut c_void) {
         sys::dispatch_set_context(self, context);
     }
+
+    /// Sets the function to call with the object's
+    /// [context](Self::context) when the object is deallocated.
+    ///
+    /// The finalizer is called on the object's target queue, and only if the
+    /// context is non-null. It is intended for releasing any resources owned
+    /// by the context, such as a `Box` created with [`Box::into_raw`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/dispatch/1452962-dispatch_set_finalizer_f).
+    ///
+    /// # Safety
+    ///
+    /// `finalizer` must be safe to call with any context that is set on the
+    /// object at the time of deallocation. It must not unwind.
+    #[inline]
+    #[doc(alias = "dispatch_set_finalizer_f")]
+    pub unsafe fn set_finalizer(&self, finalizer: Option<unsafe extern "C" fn(*mut c_void)>) {
+        sys::dispatch_set_finalizer_f(self, finalizer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        sync::atomic::{AtomicBool, Ordering},
+        thread,
+        time::{Duration, Instant},
+    };
+
+    #[test]
+    fn context() {
+        static FINALIZED: AtomicBool = AtomicBool::new(false);
+
+        unsafe extern "C" fn finalize(context: *mut c_void) {
+            drop(Box::from_raw(context.cast::<u32>()));
+            FINALIZED.store(true, Ordering::SeqCst);
+        }
+
+        let queue = DispatchQueue::builder().build();
+        assert!(queue.context().is_null());
+
+        let context = Box::into_raw(Box::new(42u32)).cast::<c_void>();
+        unsafe {
+            queue.set_context(context);
+            queue.set_finalizer(Some(finalize));
+        }
+        assert_eq!(queue.context(), context);
+
+        // Release the queue and wait for the finalizer to run on its target.
+        drop(queue);
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !FINALIZED.load(Ordering::SeqCst) {
+            assert!(Instant::now() < deadline, "finalizer was not called");
+            thread::yield_now();
+        }
+    }
 }
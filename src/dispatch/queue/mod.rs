@@ -1,8 +1,10 @@
-use super::{sys, DispatchObject, DispatchQos, DispatchQosClass};
+use super::{sys, DispatchObject, DispatchQos, DispatchQosClass, DispatchTime};
+use crate::core::ThreadSafe;
 use std::{
     ffi::{c_void, CStr, CString},
     fmt,
     mem::{self, ManuallyDrop, MaybeUninit},
+    ops::Range,
     panic, process, ptr,
 };
 
@@ -24,6 +26,9 @@ subclass! {
     pub class DispatchQueue: DispatchObject;
 }
 
+// SAFETY: Dispatch objects are designed to be used from any thread.
+unsafe impl ThreadSafe for DispatchQueue {}
+
 impl fmt::Debug for DispatchQueue {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let qos = self.qos();
@@ -46,6 +51,17 @@ impl DispatchQueue {
         unsafe { &sys::_dispatch_main_q }
     }
 
+    /// Returns the global system concurrent queue with the
+    /// [default](DispatchQosClass::Default) quality-of-service class.
+    ///
+    /// This is a shorthand for
+    /// [`global_with_qos`](Self::global_with_qos).
+    #[inline]
+    #[doc(alias = "dispatch_get_global_queue")]
+    pub fn global() -> &'static Self {
+        Self::global_with_qos(DispatchQosClass::Default)
+    }
+
     /// Returns the global system concurrent queue with the specified
     /// quality-of-service class.
     #[inline]
@@ -151,7 +167,7 @@ impl DispatchQueue {
     }
 }
 
-type DispatchFn = unsafe extern "C" fn(ctx: *mut c_void);
+pub(super) type DispatchFn = unsafe extern "C" fn(ctx: *mut c_void);
 type DispatchApplyFn = unsafe extern "C" fn(ctx: *mut c_void, iteration: usize);
 
 /// Queue operations.
@@ -316,6 +332,82 @@ impl DispatchQueue {
         }
     }
 
+    /// Calls a function the specified number of times concurrently and
+    /// collects the results in order of iteration.
+    ///
+    /// This is like [`apply_auto`](Self::apply_auto), except that the value
+    /// returned by each invocation of `f` is stored at the index of its
+    /// iteration.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/dispatch/dispatchqueue/2016088-concurrentperform) |
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/1452846-dispatch_apply_f?language=objc)
+    ///
+    /// # Availability
+    ///
+    /// - **macOS:** 10.9
+    /// - **iOS:** 7.0
+    /// - **tvOS:** 9.0
+    /// - **watchOS:** 2.0
+    ///
+    /// # Safety
+    ///
+    /// It is safe to panic within the `f` function. Panics will abort the
+    /// process.
+    #[inline]
+    #[doc(alias = "concurrentPerform")]
+    #[doc(alias = "dispatch_apply")]
+    #[doc(alias = "dispatch_apply_f")]
+    pub fn concurrent_map<T, F>(iterations: usize, f: F) -> Vec<T>
+    where
+        T: Send,
+        F: Sync + Fn(usize) -> T,
+    {
+        // Pointer to the uninitialized result slots, shared by all iterations.
+        struct Slots<T>(*mut MaybeUninit<T>);
+
+        // SAFETY: Each iteration writes only to its own slot, so sharing the
+        // pointer is equivalent to sending a `T` to each thread.
+        unsafe impl<T: Send> Sync for Slots<T> {}
+
+        impl<T> Slots<T> {
+            // Accessing the pointer through a method makes closures capture
+            // `Slots` as a whole rather than its non-`Sync` field.
+            #[inline]
+            unsafe fn write(&self, index: usize, value: T) {
+                self.0.add(index).write(MaybeUninit::new(value));
+            }
+        }
+
+        let mut results = ManuallyDrop::new(Vec::<MaybeUninit<T>>::with_capacity(iterations));
+
+        // SAFETY: `MaybeUninit` does not require initialization.
+        unsafe { results.set_len(iterations) };
+
+        let slots = Slots(results.as_mut_ptr());
+
+        Self::apply_auto(iterations, |iteration| {
+            // SAFETY: `dispatch_apply_f` calls this exactly once for each
+            // iteration in `0..iterations`, so every write is in bounds and no
+            // two writes alias.
+            unsafe { slots.write(iteration, f(iteration)) };
+        });
+
+        // SAFETY: `apply_auto` returns only after all iterations have
+        // completed, and panics abort the process rather than unwinding past
+        // this point. So every slot has been initialized exactly once.
+        //
+        // `MaybeUninit<T>` has the same layout as `T`, and `results` is never
+        // dropped, so ownership of the allocation is transferred.
+        unsafe {
+            Vec::from_raw_parts(
+                results.as_mut_ptr().cast::<T>(),
+                results.len(),
+                results.capacity(),
+            )
+        }
+    }
+
     /// Submits a function for asynchronous execution.
     ///
     /// Documentation:
@@ -400,6 +492,91 @@ impl DispatchQueue {
         }
     }
 
+    /// Submits a function for asynchronous execution at or after `when`.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/dispatch/dispatchqueue/2300020-asyncafter) |
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/1452878-dispatch_after_f?language=objc)
+    ///
+    /// # Safety
+    ///
+    /// It is safe to panic within the `work` function. Panics will abort the
+    /// process.
+    ///
+    /// If the overhead of the extra setup is undesirable or you would like to
+    /// handle panics yourself, use [`after_no_panic`](Self::after_no_panic) or
+    /// [`after_raw`](Self::after_raw) instead.
+    #[inline]
+    #[doc(alias = "dispatch_after")]
+    #[doc(alias = "dispatch_after_f")]
+    pub fn after<F>(&self, when: DispatchTime, work: F)
+    where
+        F: Send + FnOnce() + 'static,
+    {
+        // Wrap `work` to abort on panic.
+        let work = || match panic::catch_unwind(panic::AssertUnwindSafe(work)) {
+            Ok(()) => {}
+            Err(_error) => process::abort(),
+        };
+
+        // SAFETY: Any panics within `work` are caught.
+        unsafe { self.after_no_panic(when, work) };
+    }
+
+    /// Submits a function for asynchronous execution at or after `when`,
+    /// without catching panics.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/dispatch/dispatchqueue/2300020-asyncafter) |
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/1452878-dispatch_after_f?language=objc)
+    ///
+    /// # Safety
+    ///
+    /// It is undefined behavior to panic within the `work` function because it
+    /// is called from an `extern "C" fn`. Catch the panic yourself or call
+    /// [`after`](Self::after) instead.
+    #[inline]
+    #[doc(alias = "dispatch_after")]
+    #[doc(alias = "dispatch_after_f")]
+    pub unsafe fn after_no_panic<F>(&self, when: DispatchTime, work: F)
+    where
+        F: Send + FnOnce() + 'static,
+    {
+        extern "C" fn wrapped_work<F>(ctx: *mut F)
+        where
+            F: Send + FnOnce() + 'static,
+        {
+            // SAFETY: `work` is only used from within this function.
+            let work = unsafe { Box::from_raw(ctx) };
+
+            work();
+        }
+
+        self.after_raw(when, Box::into_raw(Box::new(work)), wrapped_work);
+    }
+
+    /// Submits a C function with a context pointer for asynchronous execution
+    /// at or after `when`.
+    ///
+    /// Documentation:
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/1452878-dispatch_after_f?language=objc)
+    #[inline]
+    #[doc(alias = "dispatch_after")]
+    #[doc(alias = "dispatch_after_f")]
+    pub fn after_raw<Ctx>(&self, when: DispatchTime, ctx: *mut Ctx, work: extern "C" fn(*mut Ctx)) {
+        unsafe {
+            // SAFETY: Both functions have the same ABI.
+            let work: DispatchFn = mem::transmute(work);
+
+            // SAFETY: The queue and `work` are non-null, which is required by
+            // this function.
+            //
+            // And `work` is not an `unsafe fn`, so it needs to handle safety
+            // internally.
+            sys::dispatch_after_f(when, self, ctx.cast(), work);
+        }
+    }
+
     /// Submits a function for synchronous execution and returns the function's
     /// result after it finishes executing.
     ///
@@ -598,4 +775,95 @@ impl DispatchQueue {
             sys::dispatch_apply_f(iterations, self, ctx.cast(), work);
         }
     }
+
+    /// Submits a function to execute over `0..iterations` in contiguous
+    /// chunks of at most `chunk_size` iterations.
+    ///
+    /// Each invocation of `work` is passed the range `start..end` of the
+    /// iterations in its chunk. Processing several iterations per invocation
+    /// reduces the per-iteration overhead of [`apply`](Self::apply) for
+    /// fine-grained work.
+    ///
+    /// Documentation:
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/1452846-dispatch_apply_f?language=objc)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Safety
+    ///
+    /// It is safe to panic within the `work` function. Panics will abort the
+    /// process.
+    #[inline]
+    #[doc(alias = "dispatch_apply")]
+    #[doc(alias = "dispatch_apply_f")]
+    pub fn apply_chunked<F>(&self, iterations: usize, chunk_size: usize, work: F)
+    where
+        F: Sync + Fn(Range<usize>),
+    {
+        assert_ne!(chunk_size, 0, "chunk size must be non-zero");
+
+        // Rounds up without `usize::div_ceil`, which requires Rust 1.73.
+        #[allow(clippy::manual_is_multiple_of)]
+        let chunks = iterations / chunk_size + (iterations % chunk_size != 0) as usize;
+
+        self.apply(chunks, |chunk| {
+            let start = chunk * chunk_size;
+            let end = iterations.min(start + chunk_size);
+            work(start..end);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn global() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        DispatchQueue::global().spawn_async(move || sender.send(42).unwrap());
+
+        let value = receiver.recv_timeout(std::time::Duration::from_secs(10));
+        assert_eq!(value, Ok(42));
+    }
+
+    #[test]
+    fn after() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        let start = DispatchTime::now();
+        let when = DispatchTime::from_millis_from_now(5);
+        DispatchQueue::global().after(when, move || sender.send(DispatchTime::now()).unwrap());
+
+        let ran_at = receiver
+            .recv_timeout(std::time::Duration::from_secs(10))
+            .unwrap();
+        assert!(ran_at.duration_since(start) >= std::time::Duration::from_millis(5));
+    }
+
+    #[test]
+    fn concurrent_map() {
+        let squares = DispatchQueue::concurrent_map(100, |i| i * i);
+        let expected: Vec<usize> = (0..100).map(|i| i * i).collect();
+        assert_eq!(squares, expected);
+
+        let empty = DispatchQueue::concurrent_map(0, |i| i.to_string());
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn apply_chunked() {
+        let queue = DispatchQueue::global_with_qos(DispatchQosClass::Default);
+
+        let sum = AtomicUsize::new(0);
+        queue.apply_chunked(1000, 64, |range| {
+            assert!(range.len() <= 64);
+            sum.fetch_add(range.sum(), Ordering::Relaxed);
+        });
+
+        assert_eq!(sum.into_inner(), (0..1000).sum());
+    }
 }
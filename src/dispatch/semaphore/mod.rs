@@ -0,0 +1,96 @@
+use super::{sys, DispatchObject, DispatchTime};
+use crate::core::{Arc, ThreadSafe};
+
+subclass! {
+    /// An object that controls access to a resource across multiple execution
+    /// contexts through use of a traditional counting semaphore.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/dispatch/dispatchsemaphore) |
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/dispatch_semaphore)
+    ///
+    /// # Balancing
+    ///
+    /// Every successful [`wait`](Self::wait) must be balanced by a
+    /// [`signal`](Self::signal) before the last reference to the semaphore is
+    /// dropped. libdispatch traps when a semaphore is deallocated while its
+    /// value is less than the value it was created with.
+    pub class DispatchSemaphore: DispatchObject;
+}
+
+// SAFETY: Dispatch objects are designed to be used from any thread.
+unsafe impl ThreadSafe for DispatchSemaphore {}
+
+impl DispatchSemaphore {
+    /// Creates a new counting semaphore with an initial value.
+    ///
+    /// Passing zero is useful for when two threads need to reconcile the
+    /// completion of a particular event. Passing a value greater than zero is
+    /// useful for managing a finite pool of resources, where the pool size is
+    /// equal to the value.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/dispatch/dispatchsemaphore/1452955-init) |
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/1452955-dispatch_semaphore_create?language=objc)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is negative.
+    ///
+    /// The process will also crash if the semaphore is dropped while its
+    /// value is less than `value`, so every [`wait`](Self::wait) must be
+    /// balanced by a [`signal`](Self::signal). See
+    /// [balancing](Self#balancing).
+    #[inline]
+    #[doc(alias = "dispatch_semaphore_create")]
+    pub fn new(value: isize) -> Arc<Self> {
+        assert!(value >= 0, "semaphore value must not be negative");
+        unsafe { Arc::from_raw(sys::dispatch_semaphore_create(value as _)) }
+    }
+
+    /// Signals (increments) the semaphore.
+    ///
+    /// Returns non-zero if a thread is woken. Otherwise, zero is returned.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/dispatch/dispatchsemaphore/1452919-signal) |
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/1452919-dispatch_semaphore_signal?language=objc)
+    #[inline]
+    #[doc(alias = "dispatch_semaphore_signal")]
+    pub fn signal(&self) -> isize {
+        unsafe { sys::dispatch_semaphore_signal(self) as isize }
+    }
+
+    /// Waits for (decrements) the semaphore.
+    ///
+    /// Returns `true` if the semaphore was acquired, or `false` if `timeout`
+    /// elapsed first.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/dispatch/dispatchsemaphore/2016071-wait) |
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/1453087-dispatch_semaphore_wait?language=objc)
+    #[inline]
+    #[doc(alias = "dispatch_semaphore_wait")]
+    pub fn wait(&self, timeout: DispatchTime) -> bool {
+        unsafe { sys::dispatch_semaphore_wait(self, timeout) == 0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wait_timeout() {
+        let semaphore = DispatchSemaphore::new(1);
+
+        assert!(semaphore.wait(DispatchTime::IMMEDIATE));
+        assert!(!semaphore.wait(DispatchTime::from_millis_from_now(5)));
+
+        assert_eq!(semaphore.signal(), 0);
+        assert!(semaphore.wait(DispatchTime::IMMEDIATE));
+
+        // Restore the initial value, which libdispatch requires on release.
+        semaphore.signal();
+    }
+}
@@ -1,6 +1,6 @@
 use super::{sys, DispatchObject, DispatchQueue, DispatchTime};
-use crate::core::Arc;
-use std::ptr;
+use crate::core::{Arc, ThreadSafe};
+use std::{os::raw::c_int, ptr};
 
 mod type_;
 
@@ -20,6 +20,9 @@ subclass! {
     pub class DispatchSource: DispatchObject;
 }
 
+// SAFETY: Dispatch objects are designed to be used from any thread.
+unsafe impl ThreadSafe for DispatchSource {}
+
 impl DispatchSource {
     /// Creates a new dispatch source to monitor low-level system events.
     ///
@@ -60,6 +63,75 @@ impl DispatchSource {
         ))
     }
 
+    #[inline]
+    fn create_checked(
+        source_type: &DispatchSourceType,
+        handle: usize,
+        queue: Option<&DispatchQueue>,
+    ) -> Option<Arc<Self>> {
+        let queue = match queue {
+            Some(queue) => queue,
+            None => ptr::null(),
+        };
+        unsafe {
+            let source = sys::dispatch_source_create(source_type, handle, 0, queue);
+            if source.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(source))
+            }
+        }
+    }
+
+    /// Creates a new dispatch source that monitors the current process for
+    /// the delivery of the UNIX signal `signum`.
+    ///
+    /// Unlike `sigaction`, this does not replace existing signal handlers. If
+    /// the signal would terminate the process, it must be ignored (for example,
+    /// with `SIG_IGN`) for the source to observe it.
+    ///
+    /// Returns `None` if `signum` is not a valid signal number.
+    ///
+    /// See [`create`](Self::create) and [`DispatchSourceType::signal`].
+    #[inline]
+    pub fn signal(signum: c_int, queue: Option<&DispatchQueue>) -> Option<Arc<Self>> {
+        Self::create_checked(DispatchSourceType::signal(), signum as usize, queue)
+    }
+
+    /// Creates a new dispatch source that monitors the file descriptor `fd`
+    /// for pending bytes available to be read.
+    ///
+    /// The [`data`](Self::data) of the source is an estimated number of bytes
+    /// available to be read.
+    ///
+    /// Returns `None` if `fd` is negative or the source could not be created.
+    ///
+    /// See [`create`](Self::create) and [`DispatchSourceType::read`].
+    #[inline]
+    pub fn read(fd: c_int, queue: Option<&DispatchQueue>) -> Option<Arc<Self>> {
+        if fd < 0 {
+            return None;
+        }
+        Self::create_checked(DispatchSourceType::read(), fd as usize, queue)
+    }
+
+    /// Creates a new dispatch source that monitors the file descriptor `fd`
+    /// for available buffer space to write bytes.
+    ///
+    /// The [`data`](Self::data) of the source is an estimated amount of buffer
+    /// space available.
+    ///
+    /// Returns `None` if `fd` is negative or the source could not be created.
+    ///
+    /// See [`create`](Self::create) and [`DispatchSourceType::write`].
+    #[inline]
+    pub fn write(fd: c_int, queue: Option<&DispatchQueue>) -> Option<Arc<Self>> {
+        if fd < 0 {
+            return None;
+        }
+        Self::create_checked(DispatchSourceType::write(), fd as usize, queue)
+    }
+
     /// Returns pending data for this dispatch source.
     ///
     /// Documentation:
@@ -148,3 +220,89 @@ impl DispatchSource {
         unsafe { sys::dispatch_source_testcancel(self) != 0 }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dispatch::DispatchSemaphore;
+    use std::{
+        ffi::c_void,
+        fs::File,
+        io::Write,
+        os::unix::io::{AsRawFd, FromRawFd},
+        sync::mpsc,
+        time::Duration,
+    };
+
+    #[test]
+    fn read() {
+        // Shared by the event and cancellation handlers.
+        struct Context {
+            sender: mpsc::Sender<()>,
+            cancelled: Arc<DispatchSemaphore>,
+        }
+
+        unsafe extern "C" fn handler(ctx: *mut c_void) {
+            let ctx = &*ctx.cast::<Context>();
+            let _ = ctx.sender.send(());
+        }
+
+        unsafe extern "C" fn cancel_handler(ctx: *mut c_void) {
+            let ctx = &*ctx.cast::<Context>();
+            ctx.cancelled.signal();
+        }
+
+        unsafe extern "C" fn finalize(ctx: *mut c_void) {
+            drop(Box::from_raw(ctx.cast::<Context>()));
+        }
+
+        extern "C" {
+            fn pipe(fds: *mut c_int) -> c_int;
+        }
+
+        let mut fds: [c_int; 2] = [-1; 2];
+        assert_eq!(unsafe { pipe(fds.as_mut_ptr()) }, 0);
+
+        // SAFETY: The pipe's file descriptors are owned by these files.
+        let (reader, mut writer) =
+            unsafe { (File::from_raw_fd(fds[0]), File::from_raw_fd(fds[1])) };
+        let (sender, receiver) = mpsc::channel();
+        let cancelled = DispatchSemaphore::new(0);
+
+        let source =
+            DispatchSource::read(reader.as_raw_fd(), Some(DispatchQueue::global())).unwrap();
+        assert_eq!(source.handle(), reader.as_raw_fd() as usize);
+
+        let ctx = Box::new(Context {
+            sender,
+            cancelled: cancelled.clone(),
+        });
+
+        unsafe {
+            source.set_context(Box::into_raw(ctx).cast());
+            source.set_finalizer(Some(finalize));
+            sys::dispatch_source_set_event_handler_f(&*source, handler);
+            sys::dispatch_source_set_cancel_handler_f(&*source, Some(cancel_handler));
+        }
+        source.activate();
+
+        writer.write_all(b"fruity").unwrap();
+
+        let event = receiver.recv_timeout(Duration::from_secs(10));
+        assert_eq!(event, Ok(()));
+
+        source.cancel();
+        assert!(source.is_cancelled());
+
+        // The source may only stop monitoring the file descriptor once its
+        // cancellation handler has run, so it must stay open until then.
+        assert!(cancelled.wait(DispatchTime::from_secs_from_now(10)));
+        drop(reader);
+    }
+
+    #[test]
+    fn invalid_fd() {
+        assert!(DispatchSource::read(-1, None).is_none());
+        assert!(DispatchSource::write(-1, None).is_none());
+    }
+}
@@ -1,8 +1,8 @@
 //! Raw unsafe C functions exposed by libdispatch.
 
 use super::{
-    DispatchObject, DispatchQosClass, DispatchQueue, DispatchSource, DispatchSourceType,
-    DispatchTime,
+    DispatchGroup, DispatchObject, DispatchQosClass, DispatchQueue, DispatchSemaphore,
+    DispatchSource, DispatchSourceType, DispatchTime,
 };
 use std::os::raw::{c_char, c_int, c_long, c_ulong, c_void};
 
@@ -13,6 +13,8 @@ use std::os::raw::{c_char, c_int, c_long, c_ulong, c_void};
 extern "C" {
     pub static _dispatch_main_q: DispatchQueue;
 
+    pub fn dispatch_main() -> !;
+
     pub fn dispatch_retain(obj: *const DispatchObject);
     pub fn dispatch_release(obj: *const DispatchObject);
 
@@ -22,6 +24,10 @@ extern "C" {
 
     pub fn dispatch_get_context(obj: *const DispatchObject) -> *mut c_void;
     pub fn dispatch_set_context(obj: *const DispatchObject, context: *mut c_void);
+    pub fn dispatch_set_finalizer_f(
+        obj: *const DispatchObject,
+        finalizer: Option<unsafe extern "C" fn(context: *mut c_void)>,
+    );
 
     pub fn dispatch_set_target_queue(obj: *const DispatchObject, queue: *const DispatchQueue);
     pub fn dispatch_get_global_queue(identifier: c_long, flags: c_ulong) -> *const DispatchQueue;
@@ -41,6 +47,12 @@ extern "C" {
         ctx: *mut c_void,
         work: unsafe extern "C" fn(ctx: *mut c_void),
     );
+    pub fn dispatch_after_f(
+        when: DispatchTime,
+        queue: *const DispatchQueue,
+        ctx: *mut c_void,
+        work: unsafe extern "C" fn(ctx: *mut c_void),
+    );
     pub fn dispatch_apply_f(
         iterations: usize,
         queue: *const DispatchQueue,
@@ -50,6 +62,30 @@ extern "C" {
 
     pub fn dispatch_time(when: DispatchTime, delta: i64) -> DispatchTime;
 
+    pub fn dispatch_semaphore_create(value: c_long) -> *const DispatchSemaphore;
+    pub fn dispatch_semaphore_signal(semaphore: *const DispatchSemaphore) -> c_long;
+    pub fn dispatch_semaphore_wait(
+        semaphore: *const DispatchSemaphore,
+        timeout: DispatchTime,
+    ) -> c_long;
+
+    pub fn dispatch_group_create() -> *const DispatchGroup;
+    pub fn dispatch_group_enter(group: *const DispatchGroup);
+    pub fn dispatch_group_leave(group: *const DispatchGroup);
+    pub fn dispatch_group_wait(group: *const DispatchGroup, timeout: DispatchTime) -> c_long;
+    pub fn dispatch_group_notify_f(
+        group: *const DispatchGroup,
+        queue: *const DispatchQueue,
+        ctx: *mut c_void,
+        work: unsafe extern "C" fn(ctx: *mut c_void),
+    );
+    pub fn dispatch_group_async_f(
+        group: *const DispatchGroup,
+        queue: *const DispatchQueue,
+        ctx: *mut c_void,
+        work: unsafe extern "C" fn(ctx: *mut c_void),
+    );
+
     #[doc(alias = "DISPATCH_SOURCE_TYPE_ADD")]
     pub static _dispatch_source_type_data_add: DispatchSourceType;
 
@@ -1,4 +1,5 @@
 use super::sys;
+use std::time::Duration;
 
 /// A point in time relative to the default clock, with nanosecond precision.
 ///
@@ -95,4 +96,70 @@ impl DispatchTime {
     pub fn offset_nanos(self, delta: i64) -> Self {
         unsafe { sys::dispatch_time(self, delta) }
     }
+
+    /// Returns the amount of time elapsed from `earlier` to `self`.
+    ///
+    /// Returns [`Duration::ZERO`] if `earlier` is later than `self`, and
+    /// [`Duration::MAX`] if `self` is [`DISTANT_FUTURE`](Self::DISTANT_FUTURE).
+    ///
+    /// Both times must be relative to the default clock, such as those
+    /// returned by [`now`](Self::now).
+    pub fn duration_since(&self, earlier: DispatchTime) -> Duration {
+        if *self == Self::DISTANT_FUTURE {
+            return Duration::MAX;
+        }
+
+        let ticks = self.0.saturating_sub(earlier.0);
+        let nanos = mach_ticks_to_nanos(ticks);
+
+        Duration::new(
+            (nanos / NANOS_PER_SEC as u128) as u64,
+            (nanos % NANOS_PER_SEC as u128) as u32,
+        )
+    }
+}
+
+/// Converts a number of Mach absolute time units to nanoseconds.
+fn mach_ticks_to_nanos(ticks: u64) -> u128 {
+    #[repr(C)]
+    #[derive(Default)]
+    struct MachTimebaseInfo {
+        numer: u32,
+        denom: u32,
+    }
+
+    extern "C" {
+        fn mach_timebase_info(info: *mut MachTimebaseInfo) -> i32;
+    }
+
+    let mut info = MachTimebaseInfo::default();
+    if unsafe { mach_timebase_info(&mut info) } != 0 || info.denom == 0 {
+        // Assume that ticks are nanoseconds, as they are on Intel.
+        return ticks as u128;
+    }
+
+    ticks as u128 * info.numer as u128 / info.denom as u128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn duration_since() {
+        let earlier = DispatchTime::now();
+        thread::sleep(Duration::from_millis(50));
+        let later = DispatchTime::now();
+
+        let elapsed = later.duration_since(earlier);
+        assert!(elapsed >= Duration::from_millis(50), "{:?}", elapsed);
+        assert!(elapsed < Duration::from_secs(5), "{:?}", elapsed);
+
+        assert_eq!(earlier.duration_since(later), Duration::ZERO);
+        assert_eq!(
+            DispatchTime::DISTANT_FUTURE.duration_since(earlier),
+            Duration::MAX
+        );
+    }
 }
@@ -0,0 +1,48 @@
+use super::*;
+
+typed_codes! {
+    /// An error code in the [`cocoa`](crate::foundation::NSErrorDomain::cocoa)
+    /// domain.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/cocoaerror/code).
+    CocoaError in cocoa;
+
+    FILE_NO_SUCH_FILE = NSFileNoSuchFileError,
+    FILE_LOCKING = NSFileLockingError,
+    FILE_READ_UNKNOWN = NSFileReadUnknownError,
+    FILE_READ_NO_PERMISSION = NSFileReadNoPermissionError,
+    FILE_READ_INVALID_FILE_NAME = NSFileReadInvalidFileNameError,
+    FILE_READ_CORRUPT_FILE = NSFileReadCorruptFileError,
+    FILE_READ_NO_SUCH_FILE = NSFileReadNoSuchFileError,
+    FILE_READ_INAPPLICABLE_STRING_ENCODING = NSFileReadInapplicableStringEncodingError,
+    FILE_READ_UNSUPPORTED_SCHEME = NSFileReadUnsupportedSchemeError,
+    FILE_READ_TOO_LARGE = NSFileReadTooLargeError,
+    FILE_READ_UNKNOWN_STRING_ENCODING = NSFileReadUnknownStringEncodingError,
+    FILE_WRITE_UNKNOWN = NSFileWriteUnknownError,
+    FILE_WRITE_NO_PERMISSION = NSFileWriteNoPermissionError,
+    FILE_WRITE_INVALID_FILE_NAME = NSFileWriteInvalidFileNameError,
+    FILE_WRITE_FILE_EXISTS = NSFileWriteFileExistsError,
+    FILE_WRITE_INAPPLICABLE_STRING_ENCODING = NSFileWriteInapplicableStringEncodingError,
+    FILE_WRITE_UNSUPPORTED_SCHEME = NSFileWriteUnsupportedSchemeError,
+    FILE_WRITE_OUT_OF_SPACE = NSFileWriteOutOfSpaceError,
+    FILE_WRITE_VOLUME_READ_ONLY = NSFileWriteVolumeReadOnlyError,
+    KEY_VALUE_VALIDATION = NSKeyValueValidationError,
+    FORMATTING = NSFormattingError,
+    USER_CANCELLED = NSUserCancelledError,
+    FEATURE_UNSUPPORTED = NSFeatureUnsupportedError,
+    EXECUTABLE_NOT_LOADABLE = NSExecutableNotLoadableError,
+    EXECUTABLE_ARCHITECTURE_MISMATCH = NSExecutableArchitectureMismatchError,
+    EXECUTABLE_RUNTIME_MISMATCH = NSExecutableRuntimeMismatchError,
+    EXECUTABLE_LOAD = NSExecutableLoadError,
+    EXECUTABLE_LINK = NSExecutableLinkError,
+    PROPERTY_LIST_READ_CORRUPT = NSPropertyListReadCorruptError,
+    PROPERTY_LIST_READ_UNKNOWN_VERSION = NSPropertyListReadUnknownVersionError,
+    PROPERTY_LIST_READ_STREAM = NSPropertyListReadStreamError,
+    PROPERTY_LIST_WRITE_STREAM = NSPropertyListWriteStreamError,
+    PROPERTY_LIST_WRITE_INVALID = NSPropertyListWriteInvalidError,
+    CODER_READ_CORRUPT = NSCoderReadCorruptError,
+    CODER_VALUE_NOT_FOUND = NSCoderValueNotFoundError,
+    CODER_INVALID_VALUE = NSCoderInvalidValueError,
+    COMPRESSION_FAILED = NSCompressionFailedError,
+    DECOMPRESSION_FAILED = NSDecompressionFailedError,
+}
@@ -8,6 +8,48 @@
 
 use crate::objc::NSInteger;
 
+/// Defines a `#[repr(transparent)]` newtype over `NSInteger` with associated
+/// error code constants.
+macro_rules! typed_codes {
+    (
+        $(#[$meta:meta])+
+        $name:ident in $domain:ident;
+        $($code:ident = $value:expr,)+
+    ) => {
+        $(#[$meta])+
+        #[repr(transparent)]
+        #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+        pub struct $name(pub NSInteger);
+
+        impl From<$name> for NSInteger {
+            #[inline]
+            fn from(code: $name) -> Self {
+                code.0
+            }
+        }
+
+        impl $name {
+            $(
+                pub const $code: Self = Self($value);
+            )+
+
+            /// Returns the error domain of these codes.
+            #[inline]
+            pub fn domain() -> &'static crate::foundation::NSErrorDomain {
+                crate::foundation::NSErrorDomain::$domain()
+            }
+        }
+    };
+}
+
+mod cocoa;
+mod posix;
+mod url;
+
+pub use cocoa::*;
+pub use posix::*;
+pub use url::*;
+
 macro_rules! codes {
     ($($name:ident = $value:expr,)+) => {
         $(
@@ -188,3 +230,19 @@ codes! {
     NSURLErrorBackgroundSessionInUseByAnotherProcess = -996,
     NSURLErrorBackgroundSessionWasDisconnected = -997,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::foundation::NSError;
+
+    #[test]
+    fn cocoa_error_code() {
+        assert_eq!(CocoaError::FILE_NO_SUCH_FILE.0, NSFileNoSuchFileError);
+
+        let error = NSError::new(CocoaError::domain(), CocoaError::FILE_NO_SUCH_FILE.0, None);
+
+        assert_eq!(error.code(), CocoaError::FILE_NO_SUCH_FILE.0);
+        assert!(*error.domain() == *CocoaError::domain());
+    }
+}
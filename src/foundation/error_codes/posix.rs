@@ -0,0 +1,65 @@
+use super::*;
+
+typed_codes! {
+    /// An error code in the [`posix`](crate::foundation::NSErrorDomain::posix)
+    /// domain.
+    ///
+    /// These are the `errno` values from `<sys/errno.h>`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/posixerror/code).
+    POSIXError in posix;
+
+    EPERM = 1,
+    ENOENT = 2,
+    ESRCH = 3,
+    EINTR = 4,
+    EIO = 5,
+    ENXIO = 6,
+    E2BIG = 7,
+    ENOEXEC = 8,
+    EBADF = 9,
+    ECHILD = 10,
+    EDEADLK = 11,
+    ENOMEM = 12,
+    EACCES = 13,
+    EFAULT = 14,
+    EBUSY = 16,
+    EEXIST = 17,
+    EXDEV = 18,
+    ENODEV = 19,
+    ENOTDIR = 20,
+    EISDIR = 21,
+    EINVAL = 22,
+    ENFILE = 23,
+    EMFILE = 24,
+    ENOTTY = 25,
+    EFBIG = 27,
+    ENOSPC = 28,
+    ESPIPE = 29,
+    EROFS = 30,
+    EMLINK = 31,
+    EPIPE = 32,
+    EAGAIN = 35,
+    EINPROGRESS = 36,
+    EALREADY = 37,
+    ENOTSOCK = 38,
+    ENOTSUP = 45,
+    EADDRINUSE = 48,
+    EADDRNOTAVAIL = 49,
+    ENETDOWN = 50,
+    ENETUNREACH = 51,
+    ECONNABORTED = 53,
+    ECONNRESET = 54,
+    ENOBUFS = 55,
+    EISCONN = 56,
+    ENOTCONN = 57,
+    ETIMEDOUT = 60,
+    ECONNREFUSED = 61,
+    ELOOP = 62,
+    ENAMETOOLONG = 63,
+    EHOSTUNREACH = 65,
+    ENOTEMPTY = 66,
+    ENOSYS = 78,
+    EOVERFLOW = 84,
+    ECANCELED = 89,
+}
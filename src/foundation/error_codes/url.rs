@@ -0,0 +1,50 @@
+use super::*;
+
+typed_codes! {
+    /// An error code in the [`ns_url`](crate::foundation::NSErrorDomain::ns_url)
+    /// domain.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlerror/code).
+    URLError in ns_url;
+
+    UNKNOWN = NSURLErrorUnknown,
+    CANCELLED = NSURLErrorCancelled,
+    BAD_URL = NSURLErrorBadURL,
+    TIMED_OUT = NSURLErrorTimedOut,
+    UNSUPPORTED_URL = NSURLErrorUnsupportedURL,
+    CANNOT_FIND_HOST = NSURLErrorCannotFindHost,
+    CANNOT_CONNECT_TO_HOST = NSURLErrorCannotConnectToHost,
+    NETWORK_CONNECTION_LOST = NSURLErrorNetworkConnectionLost,
+    DNS_LOOKUP_FAILED = NSURLErrorDNSLookupFailed,
+    HTTP_TOO_MANY_REDIRECTS = NSURLErrorHTTPTooManyRedirects,
+    RESOURCE_UNAVAILABLE = NSURLErrorResourceUnavailable,
+    NOT_CONNECTED_TO_INTERNET = NSURLErrorNotConnectedToInternet,
+    REDIRECT_TO_NON_EXISTENT_LOCATION = NSURLErrorRedirectToNonExistentLocation,
+    BAD_SERVER_RESPONSE = NSURLErrorBadServerResponse,
+    USER_CANCELLED_AUTHENTICATION = NSURLErrorUserCancelledAuthentication,
+    USER_AUTHENTICATION_REQUIRED = NSURLErrorUserAuthenticationRequired,
+    ZERO_BYTE_RESOURCE = NSURLErrorZeroByteResource,
+    CANNOT_DECODE_RAW_DATA = NSURLErrorCannotDecodeRawData,
+    CANNOT_DECODE_CONTENT_DATA = NSURLErrorCannotDecodeContentData,
+    CANNOT_PARSE_RESPONSE = NSURLErrorCannotParseResponse,
+    APP_TRANSPORT_SECURITY_REQUIRES_SECURE_CONNECTION =
+        NSURLErrorAppTransportSecurityRequiresSecureConnection,
+    FILE_DOES_NOT_EXIST = NSURLErrorFileDoesNotExist,
+    FILE_IS_DIRECTORY = NSURLErrorFileIsDirectory,
+    NO_PERMISSIONS_TO_READ_FILE = NSURLErrorNoPermissionsToReadFile,
+    DATA_LENGTH_EXCEEDS_MAXIMUM = NSURLErrorDataLengthExceedsMaximum,
+    SECURE_CONNECTION_FAILED = NSURLErrorSecureConnectionFailed,
+    SERVER_CERTIFICATE_HAS_BAD_DATE = NSURLErrorServerCertificateHasBadDate,
+    SERVER_CERTIFICATE_UNTRUSTED = NSURLErrorServerCertificateUntrusted,
+    SERVER_CERTIFICATE_HAS_UNKNOWN_ROOT = NSURLErrorServerCertificateHasUnknownRoot,
+    SERVER_CERTIFICATE_NOT_YET_VALID = NSURLErrorServerCertificateNotYetValid,
+    CLIENT_CERTIFICATE_REJECTED = NSURLErrorClientCertificateRejected,
+    CLIENT_CERTIFICATE_REQUIRED = NSURLErrorClientCertificateRequired,
+    CANNOT_LOAD_FROM_NETWORK = NSURLErrorCannotLoadFromNetwork,
+    CANNOT_CREATE_FILE = NSURLErrorCannotCreateFile,
+    CANNOT_OPEN_FILE = NSURLErrorCannotOpenFile,
+    CANNOT_CLOSE_FILE = NSURLErrorCannotCloseFile,
+    CANNOT_WRITE_TO_FILE = NSURLErrorCannotWriteToFile,
+    CANNOT_REMOVE_FILE = NSURLErrorCannotRemoveFile,
+    CANNOT_MOVE_FILE = NSURLErrorCannotMoveFile,
+}
@@ -0,0 +1,53 @@
+/// Defines a `#[repr(transparent)]` newtype over `NSUInteger` for bit-flag
+/// options, along with `|` and `&` operators and a `contains` method.
+macro_rules! options {
+    (
+        $(#[$meta:meta])+
+        pub struct $name:ident;
+    ) => {
+        $(#[$meta])+
+        #[repr(transparent)]
+        #[derive(Copy, Clone, Debug, Default, Ord, PartialOrd, Eq, PartialEq, Hash)]
+        pub struct $name(pub crate::objc::NSUInteger);
+
+        impl std::ops::BitOr for $name {
+            type Output = Self;
+
+            #[inline]
+            fn bitor(self, other: Self) -> Self {
+                Self(self.0 | other.0)
+            }
+        }
+
+        impl std::ops::BitOrAssign for $name {
+            #[inline]
+            fn bitor_assign(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+        }
+
+        impl std::ops::BitAnd for $name {
+            type Output = Self;
+
+            #[inline]
+            fn bitand(self, other: Self) -> Self {
+                Self(self.0 & other.0)
+            }
+        }
+
+        impl std::ops::BitAndAssign for $name {
+            #[inline]
+            fn bitand_assign(&mut self, other: Self) {
+                self.0 &= other.0;
+            }
+        }
+
+        impl $name {
+            /// Returns `true` if all flags in `other` are set in `self`.
+            #[inline]
+            pub const fn contains(self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+        }
+    };
+}
@@ -10,6 +10,9 @@
 
 #![cfg(feature = "foundation")]
 
+#[macro_use]
+mod macros;
+
 #[macro_use]
 mod ns_string;
 
@@ -17,21 +20,47 @@ pub mod error_codes;
 
 mod cmp;
 mod geometry;
+mod ns_array;
+mod ns_byte_count_formatter;
+mod ns_character_set;
+mod ns_data;
+mod ns_date;
+mod ns_date_formatter;
+mod ns_dictionary;
 mod ns_error;
 mod ns_exception;
+mod ns_formatter;
+mod ns_locale;
 mod ns_null;
 mod ns_number;
+mod ns_number_formatter;
+mod ns_predicate;
+mod ns_process_info;
 mod ns_range;
+mod ns_url;
 mod ns_value;
 
 pub use cmp::*;
 pub use geometry::*;
+pub use ns_array::*;
+pub use ns_byte_count_formatter::*;
+pub use ns_character_set::*;
+pub use ns_data::*;
+pub use ns_date::*;
+pub use ns_date_formatter::*;
+pub use ns_dictionary::*;
 pub use ns_error::*;
 pub use ns_exception::*;
+pub use ns_formatter::*;
+pub use ns_locale::*;
 pub use ns_null::*;
 pub use ns_number::*;
+pub use ns_number_formatter::*;
+pub use ns_predicate::*;
+pub use ns_process_info::*;
 pub use ns_range::*;
 pub use ns_string::*;
+pub use ns_url::*;
 pub use ns_value::*;
 
 /// A number of seconds.
@@ -0,0 +1,69 @@
+use super::NSArray;
+use crate::core::Arc;
+use crate::objc::ObjectType;
+use std::{iter::FusedIterator, ops::Range};
+
+/// An iterator over the objects of an [`NSArray`].
+///
+/// This struct is created by [`NSArray::iter`]. See its documentation for
+/// more.
+pub struct NSArrayIter<'a, 'data, T> {
+    array: &'a NSArray<'data, T>,
+    // The count is snapshotted when the iterator is created.
+    indices: Range<usize>,
+}
+
+// Not derived to avoid requiring `T: Clone`.
+impl<T> Clone for NSArrayIter<'_, '_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            array: self.array,
+            indices: self.indices.clone(),
+        }
+    }
+}
+
+impl<'a, 'data, T: ObjectType<'data>> NSArrayIter<'a, 'data, T> {
+    #[inline]
+    pub(super) fn new(array: &'a NSArray<'data, T>) -> Self {
+        Self {
+            array,
+            indices: 0..array.count(),
+        }
+    }
+}
+
+impl<'a, 'data, T: ObjectType<'data>> Iterator for NSArrayIter<'a, 'data, T> {
+    type Item = Arc<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next()?;
+        Some(self.array.object_at_index(index))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<'data, T: ObjectType<'data>> ExactSizeIterator for NSArrayIter<'_, 'data, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+}
+
+impl<'data, T: ObjectType<'data>> FusedIterator for NSArrayIter<'_, 'data, T> {}
+
+impl<'a, 'data, T: ObjectType<'data>> IntoIterator for &'a NSArray<'data, T> {
+    type Item = Arc<T>;
+    type IntoIter = NSArrayIter<'a, 'data, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
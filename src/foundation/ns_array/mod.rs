@@ -0,0 +1,631 @@
+use crate::core::Arc;
+use crate::foundation::{NSNotFound, NSPredicate};
+use crate::objc::{
+    Class, ClassType, Copying, MutableCopying, NSObject, NSUInteger, ObjCObject, ObjectType,
+    StackBlock, BOOL,
+};
+use std::{
+    ffi::c_void,
+    fmt,
+    iter::Enumerate,
+    marker::PhantomData,
+    ops::{Deref, Index},
+    panic, process,
+    ptr::NonNull,
+};
+
+mod iter;
+mod mutable;
+
+pub use iter::*;
+pub use mutable::*;
+
+/// A static ordered collection of objects.
+///
+/// The type parameter `T` is the type of the objects stored in the array. It
+/// defaults to [`ObjCObject`] for arrays whose elements are not statically
+/// known.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsarray).
+#[repr(C)]
+pub struct NSArray<'data, T = ObjCObject<'data>> {
+    base: NSObject<'data>,
+    marker: PhantomData<T>,
+}
+
+impl<T> crate::core::ObjectType for NSArray<'_, T> {
+    #[inline]
+    fn retain(obj: &Self) -> Arc<Self> {
+        let obj = Arc::retain(&obj.base);
+        unsafe { Arc::cast_unchecked(obj) }
+    }
+
+    #[inline]
+    unsafe fn release(obj: NonNull<Self>) {
+        NSObject::release(obj.cast());
+    }
+}
+
+impl<'data, T: 'data> ObjectType<'data> for NSArray<'data, T> {}
+
+impl<'data, T: 'data> ClassType<'data> for NSArray<'data, T> {
+    #[inline]
+    unsafe fn direct_class() -> &'static Class {
+        crate::_objc_class!(@ "OBJC_CLASS_$_NSArray")
+    }
+}
+
+// SAFETY: Copying an array returns an immutable array of the same elements.
+unsafe impl<'data, T: 'data> Copying<'data> for NSArray<'data, T> {}
+
+// SAFETY: Mutable copies of arrays contain the same elements.
+unsafe impl<'data, T: 'data> MutableCopying<'data> for NSArray<'data, T> {
+    type Mutable = NSMutableArray<'data, T>;
+}
+
+impl<'data, T> Deref for NSArray<'data, T> {
+    type Target = NSObject<'data>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'data, T> AsRef<NSArray<'data, T>> for NSArray<'data, T> {
+    #[inline]
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl<'data, T, U> AsRef<U> for NSArray<'data, T>
+where
+    NSObject<'data>: AsRef<U>,
+{
+    #[inline]
+    fn as_ref(&self) -> &U {
+        self.base.as_ref()
+    }
+}
+
+impl<'data, T> fmt::Debug for NSArray<'data, T>
+where
+    T: ObjectType<'data> + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.to_vec()).finish()
+    }
+}
+
+impl<'data, T: ObjectType<'data>> PartialEq for NSArray<'data, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.is_equal_to_array(other)
+    }
+}
+
+impl<'data, T: ObjectType<'data>> Eq for NSArray<'data, T> {}
+
+impl<'data, T> PartialEq<[Arc<T>]> for NSArray<'data, T>
+where
+    T: ObjectType<'data> + PartialEq,
+{
+    fn eq(&self, other: &[Arc<T>]) -> bool {
+        let count = self.count();
+        if count != other.len() {
+            return false;
+        }
+        (0..count).all(|index| *self.object_at_index(index) == *other[index])
+    }
+}
+
+impl<'data, T: ObjectType<'data>> From<&[Arc<T>]> for Arc<NSArray<'data, T>> {
+    #[inline]
+    fn from(objects: &[Arc<T>]) -> Self {
+        NSArray::from_slice(objects)
+    }
+}
+
+impl<'data, T: ObjectType<'data>> From<Vec<Arc<T>>> for Arc<NSArray<'data, T>> {
+    #[inline]
+    fn from(objects: Vec<Arc<T>>) -> Self {
+        NSArray::from_slice(&objects)
+    }
+}
+
+/// Creating arrays.
+impl<'data, T: ObjectType<'data>> NSArray<'data, T> {
+    /// Creates an array containing the objects in a slice.
+    ///
+    /// Each object is retained by the returned array. The slice is passed to
+    /// Objective-C as a pointer array without a temporary allocation. An empty
+    /// slice creates an empty array.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/1460068-initwithobjects).
+    #[inline]
+    #[doc(alias = "initWithObjects:count:")]
+    #[doc(alias = "arrayWithObjects:count:")]
+    pub fn from_slice(objects: &[Arc<T>]) -> Arc<Self> {
+        let objects = Arc::slice_as_ptr_array(objects);
+
+        unsafe {
+            let value: Arc<Self> = <Self as ClassType>::class().alloc();
+
+            // The initializer consumes the allocated instance.
+            let value = &*Arc::into_raw(value);
+
+            _msg_send_strict![
+                value,
+                initWithObjects: objects.as_ptr()
+                count: objects.len() as NSUInteger
+                => Arc<Self>
+            ]
+        }
+    }
+}
+
+/// Accessing elements.
+impl<'data, T: ObjectType<'data>> NSArray<'data, T> {
+    /// Returns the number of objects in this array.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/1409982-count).
+    #[inline]
+    pub fn count(&self) -> usize {
+        unsafe { _msg_send_any![self, count => NSUInteger] }
+    }
+
+    /// Returns `true` if this array contains no objects.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.count() == 0
+    }
+
+    /// Returns the object located at `index`.
+    ///
+    /// # Panics
+    ///
+    /// An Objective-C exception is raised if `index` is out of bounds.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/1417555-objectatindex).
+    #[inline]
+    #[doc(alias = "objectAtIndex")]
+    #[doc(alias = "objectAtIndex:")]
+    pub fn object_at_index(&self, index: usize) -> Arc<T> {
+        // SAFETY: The returned object is not owned by the caller, so it must
+        // be retained.
+        let object: &T =
+            unsafe { _msg_send_strict![self, objectAtIndex: index as NSUInteger => &T] };
+        Arc::retain(object)
+    }
+
+    /// Returns the object located at `index`, or `None` if `index` is out of
+    /// bounds.
+    ///
+    /// Unlike [`object_at_index`](Self::object_at_index), this never raises an
+    /// exception.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<Arc<T>> {
+        if index < self.count() {
+            Some(self.object_at_index(index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the objects of `other` are equal to those of `self`.
+    ///
+    /// Two arrays have equal contents if they each hold the same number of
+    /// objects and objects at a given index in each array satisfy the
+    /// `isEqual:` test.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/1411770-isequaltoarray).
+    #[inline]
+    #[doc(alias = "isEqualToArray")]
+    #[doc(alias = "isEqualToArray:")]
+    pub fn is_equal_to_array(&self, other: &NSArray<'data, T>) -> bool {
+        unsafe { _msg_send_strict![self, isEqualToArray: other => BOOL] }.into()
+    }
+
+    /// Returns an iterator over the objects of this array.
+    ///
+    /// The number of objects is determined when the iterator is created.
+    #[inline]
+    pub fn iter(&self) -> NSArrayIter<'_, 'data, T> {
+        NSArrayIter::new(self)
+    }
+
+    /// Returns an iterator over the objects of this array along with their
+    /// indices.
+    #[inline]
+    pub fn enumerate(&self) -> Enumerate<NSArrayIter<'_, 'data, T>> {
+        self.iter().enumerate()
+    }
+
+    /// Folds every object of this array into an accumulator by applying `f`,
+    /// in order.
+    ///
+    /// This is equivalent to [`Iterator::fold`] on [`iter`](Self::iter).
+    #[inline]
+    pub fn fold<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Arc<T>) -> B,
+    {
+        self.iter().fold(init, f)
+    }
+
+    /// Returns the objects of this array in a `Vec`.
+    #[inline]
+    pub fn to_vec(&self) -> Vec<Arc<T>> {
+        self.map_to_vec(|object| object)
+    }
+
+    /// Returns a snapshot of the objects of this array that can be indexed
+    /// with `snapshot[index]`.
+    ///
+    /// [`Index`] cannot be implemented for `NSArray` itself because each
+    /// object must be retained before it is returned.
+    #[inline]
+    pub fn snapshot(&self) -> ArraySnapshot<T> {
+        ArraySnapshot {
+            objects: self.to_vec(),
+        }
+    }
+
+    /// Returns the results of calling `f` on each object of this array, in
+    /// order.
+    pub fn map_to_vec<U, F>(&self, mut f: F) -> Vec<U>
+    where
+        F: FnMut(Arc<T>) -> U,
+    {
+        let len = self.count();
+        let mut vec = Vec::with_capacity(len);
+        for index in 0..len {
+            vec.push(f(self.object_at_index(index)));
+        }
+        vec
+    }
+}
+
+/// The objects of an [`NSArray`] at some point in time.
+///
+/// This is created by [`NSArray::snapshot`]. Unlike `NSArray`, it can be
+/// indexed directly.
+#[derive(Clone)]
+pub struct ArraySnapshot<T: crate::core::ObjectType> {
+    objects: Vec<Arc<T>>,
+}
+
+impl<T: crate::core::ObjectType + fmt::Debug> fmt::Debug for ArraySnapshot<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.objects.fmt(f)
+    }
+}
+
+impl<T: crate::core::ObjectType> Deref for ArraySnapshot<T> {
+    type Target = [Arc<T>];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.objects
+    }
+}
+
+impl<T: crate::core::ObjectType> Index<usize> for ArraySnapshot<T> {
+    type Output = Arc<T>;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.objects[index]
+    }
+}
+
+impl<T: crate::core::ObjectType> From<ArraySnapshot<T>> for Vec<Arc<T>> {
+    #[inline]
+    fn from(snapshot: ArraySnapshot<T>) -> Self {
+        snapshot.objects
+    }
+}
+
+/// Searching.
+impl<'data, T: ObjectType<'data>> NSArray<'data, T> {
+    /// Returns the index of the first object of this array for which `f`
+    /// returns `true`, or `None` if there is no such object.
+    ///
+    /// `f` is called with each object and its index, in order.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/1412063-indexofobjectpassingtest).
+    ///
+    /// # Safety
+    ///
+    /// It is safe to panic within the `f` function. Panics will abort the
+    /// process.
+    #[inline]
+    #[doc(alias = "indexOfObjectPassingTest")]
+    #[doc(alias = "indexOfObjectPassingTest:")]
+    pub fn index_of_object_passing_test<F>(&self, f: F) -> Option<NSUInteger>
+    where
+        F: FnMut(Arc<T>, NSUInteger) -> bool,
+    {
+        extern "C" fn invoke<T, F>(
+            block: *mut StackBlock<F>,
+            object: &T,
+            index: NSUInteger,
+            _stop: *mut BOOL,
+        ) -> BOOL
+        where
+            T: crate::core::ObjectType,
+            F: FnMut(Arc<T>, NSUInteger) -> bool,
+        {
+            // SAFETY: The block is only called during
+            // `indexOfObjectPassingTest:`.
+            let f = unsafe { StackBlock::closure(block) };
+
+            match panic::catch_unwind(panic::AssertUnwindSafe(|| f(Arc::retain(object), index))) {
+                Ok(passed) => passed.into(),
+                Err(_error) => process::abort(),
+            }
+        }
+
+        let invoke: extern "C" fn(_, &T, _, _) -> _ = invoke::<T, F>;
+
+        // SAFETY: `invoke` matches the block type expected by
+        // `indexOfObjectPassingTest:`, which takes an object, its index, and a
+        // stop flag, and returns `BOOL`.
+        let mut block = unsafe { StackBlock::new(f, invoke as *const c_void) };
+
+        let index: NSUInteger =
+            unsafe { _msg_send_strict![self, indexOfObjectPassingTest: &mut block] };
+
+        if index == NSNotFound as NSUInteger {
+            None
+        } else {
+            Some(index)
+        }
+    }
+
+    /// Returns the first object of this array for which `f` returns `true`, or
+    /// `None` if there is no such object.
+    ///
+    /// This is a convenience wrapper around
+    /// [`index_of_object_passing_test`](Self::index_of_object_passing_test)
+    /// that ignores indices.
+    ///
+    /// # Safety
+    ///
+    /// It is safe to panic within the `f` function. Panics will abort the
+    /// process.
+    #[inline]
+    pub fn find<F>(&self, mut f: F) -> Option<Arc<T>>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut found = None;
+        self.index_of_object_passing_test(|object, _index| {
+            let passed = f(&object);
+            if passed {
+                found = Some(object);
+            }
+            passed
+        });
+        found
+    }
+}
+
+/// Deriving new arrays.
+impl<'data, T: ObjectType<'data>> NSArray<'data, T> {
+    /// Returns a new array containing the objects of this array that match
+    /// `predicate`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/1406363-filteredarrayusingpredicate).
+    ///
+    /// # Exception Handling
+    ///
+    /// If `predicate` cannot be evaluated on an object of this array, an
+    /// `NSInvalidArgumentException` is thrown.
+    #[inline]
+    #[doc(alias = "filteredArrayUsingPredicate")]
+    #[doc(alias = "filteredArrayUsingPredicate:")]
+    pub fn filtered(&self, predicate: &NSPredicate) -> Arc<Self> {
+        let filtered: &Self =
+            unsafe { _msg_send_strict![self, filteredArrayUsingPredicate: predicate] };
+        Arc::retain(filtered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::foundation::NSString;
+
+    #[test]
+    fn from_vec() {
+        let strings = vec![
+            NSString::from_str("a"),
+            NSString::from_str("b"),
+            NSString::from_str("c"),
+        ];
+
+        let array: Arc<NSArray<NSString>> = strings.clone().into();
+        assert_eq!(array.count(), strings.len());
+
+        let result = array.map_to_vec(|s| s.to_string());
+        assert_eq!(result, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn from_slice() {
+        let strings = [
+            NSString::from_str("a"),
+            NSString::from_str("b"),
+            NSString::from_str("c"),
+        ];
+        let array = NSArray::from_slice(&strings);
+        assert_eq!(array.count(), strings.len());
+
+        for (index, string) in strings.iter().enumerate() {
+            let object = array.object_at_index(index);
+            // Arrays retain the objects themselves rather than copies.
+            assert_eq!(&*object as *const NSString, &**string as *const NSString);
+            assert_eq!(object.to_string(), string.to_string());
+        }
+    }
+
+    #[test]
+    fn get() {
+        let strings = [NSString::from_str("a"), NSString::from_str("b")];
+        let array = NSArray::from_slice(&strings);
+
+        assert_eq!(array.get(1).map(|s| s.to_string()), Some("b".into()));
+        assert!(array.get(array.count()).is_none());
+        assert!(array.get(usize::MAX).is_none());
+    }
+
+    #[test]
+    fn snapshot() {
+        let strings = [NSString::from_str("a"), NSString::from_str("b")];
+        let array = NSArray::from_slice(&strings);
+
+        let snapshot = array.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot[1].to_string(), "b");
+    }
+
+    fn array_of(strings: &[&str]) -> Arc<NSArray<'static, NSString<'static>>> {
+        let strings: Vec<_> = strings.iter().map(|s| NSString::from_str(s)).collect();
+        NSArray::from_slice(&strings)
+    }
+
+    #[test]
+    fn iter() {
+        let array = array_of(&["a", "b", "c"]);
+
+        let mut iter = array.iter();
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        assert_eq!(iter.len(), 2);
+
+        // Clones resume from the same position.
+        let clone = iter.clone();
+        let rest: Vec<String> = clone.map(|s| s.to_string()).collect();
+        assert_eq!(rest, ["b", "c"]);
+        assert_eq!(iter.len(), 2);
+
+        let strings: Vec<Arc<NSString>> = array.iter().collect();
+        assert_eq!(strings.len(), 3);
+        assert_eq!(strings[2].to_string(), "c");
+
+        let mut joined = String::new();
+        for s in &*array {
+            joined.push_str(&s.to_string());
+        }
+        assert_eq!(joined, "abc");
+
+        let empty = array_of(&[]);
+        let mut iter = empty.iter();
+        assert_eq!(iter.len(), 0);
+        assert!(iter.next().is_none());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn fold() {
+        use crate::foundation::NSNumber;
+
+        let values = [3, 1, 4, 1, 5, 9];
+        let numbers = values.map(NSNumber::from_int);
+        let array = NSArray::from_slice(&numbers);
+
+        let total = array.fold(0, |total, number| total + number.int_value());
+        assert_eq!(total, values.iter().sum());
+
+        let joined = array_of(&["a", "b", "c"]).fold(String::new(), |mut joined, s| {
+            joined.push_str(&s.to_string());
+            joined
+        });
+        assert_eq!(joined, "abc");
+    }
+
+    #[test]
+    fn enumerate() {
+        let strings = [
+            NSString::from_str("a"),
+            NSString::from_str("b"),
+            NSString::from_str("c"),
+        ];
+        let array = NSArray::from_slice(&strings);
+
+        let pairs: Vec<(usize, String)> = array
+            .enumerate()
+            .map(|(index, s)| (index, s.to_string()))
+            .collect();
+        assert_eq!(pairs, [(0, "a".into()), (1, "b".into()), (2, "c".into())]);
+    }
+
+    #[test]
+    fn index_of_object_passing_test() {
+        use crate::foundation::NSNumber;
+
+        let numbers = [1, 5, 2, 7].map(NSNumber::from_int);
+        let array = NSArray::from_slice(&numbers);
+
+        let mut visited = Vec::new();
+        let index = array.index_of_object_passing_test(|number, index| {
+            visited.push(index);
+            number.int_value() > 3
+        });
+        assert_eq!(index, Some(1));
+        assert_eq!(visited, [0, 1]);
+
+        let found = array.find(|number| number.int_value() > 5);
+        assert_eq!(found.map(|number| number.int_value()), Some(7));
+
+        assert_eq!(array.index_of_object_passing_test(|_, _| false), None);
+        assert!(array.find(|number| number.int_value() > 10).is_none());
+    }
+
+    #[test]
+    fn filtered() {
+        let strings = [
+            NSString::from_str("apple"),
+            NSString::from_str("banana"),
+            NSString::from_str("avocado"),
+        ];
+        let array = NSArray::from_slice(&strings);
+
+        let predicate = NSPredicate::from_format(ns_string!("SELF BEGINSWITH 'a'"));
+        let filtered = array.filtered(&predicate);
+        assert_eq!(filtered.map_to_vec(|s| s.to_string()), ["apple", "avocado"]);
+    }
+
+    #[test]
+    fn eq() {
+        let a = NSArray::from_slice(&[NSString::from_str("a"), NSString::from_str("b")]);
+        let b = NSArray::from_slice(&[NSString::from_str("a"), NSString::from_str("b")]);
+        let c = NSArray::from_slice(&[NSString::from_str("a")]);
+
+        assert_eq!(*a, *b);
+        assert_ne!(*a, *c);
+    }
+
+    #[test]
+    fn eq_slice() {
+        let strings = [NSString::from_str("a"), NSString::from_str("b")];
+        let array = NSArray::from_slice(&strings);
+
+        assert_eq!(*array, strings[..]);
+        assert_ne!(*array, strings[..1]);
+        assert_ne!(
+            *array,
+            [NSString::from_str("a"), NSString::from_str("c")][..]
+        );
+    }
+
+    #[test]
+    fn from_empty_slice() {
+        let array: Arc<NSArray<NSString>> = (&[][..]).into();
+        assert!(array.is_empty());
+        assert!(array.to_vec().is_empty());
+    }
+}
@@ -0,0 +1,193 @@
+use super::NSArray;
+use crate::core::Arc;
+use crate::foundation::NSComparisonResult;
+use crate::objc::{
+    Class, ClassType, MutableCopying, NSUInteger, ObjCObject, ObjectType, StackBlock,
+};
+use std::{cmp::Ordering, ffi::c_void, fmt, ops::Deref, panic, process, ptr::NonNull};
+
+/// A dynamic ordered collection of objects.
+///
+/// The type parameter `T` is the type of the objects stored in the array. It
+/// defaults to [`ObjCObject`] for arrays whose elements are not statically
+/// known.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsmutablearray).
+#[repr(C)]
+pub struct NSMutableArray<'data, T = ObjCObject<'data>> {
+    base: NSArray<'data, T>,
+}
+
+impl<T> crate::core::ObjectType for NSMutableArray<'_, T> {
+    #[inline]
+    fn retain(obj: &Self) -> Arc<Self> {
+        let obj = Arc::retain(&obj.base);
+        unsafe { Arc::cast_unchecked(obj) }
+    }
+
+    #[inline]
+    unsafe fn release(obj: NonNull<Self>) {
+        NSArray::<T>::release(obj.cast());
+    }
+}
+
+impl<'data, T: 'data> ObjectType<'data> for NSMutableArray<'data, T> {}
+
+impl<'data, T: 'data> ClassType<'data> for NSMutableArray<'data, T> {
+    #[inline]
+    unsafe fn direct_class() -> &'static Class {
+        crate::_objc_class!(@ "OBJC_CLASS_$_NSMutableArray")
+    }
+}
+
+// SAFETY: Mutable copies of arrays contain the same elements.
+unsafe impl<'data, T: 'data> MutableCopying<'data> for NSMutableArray<'data, T> {
+    type Mutable = Self;
+}
+
+impl<'data, T> Deref for NSMutableArray<'data, T> {
+    type Target = NSArray<'data, T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'data, T> AsRef<NSMutableArray<'data, T>> for NSMutableArray<'data, T> {
+    #[inline]
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl<'data, T, U> AsRef<U> for NSMutableArray<'data, T>
+where
+    NSArray<'data, T>: AsRef<U>,
+{
+    #[inline]
+    fn as_ref(&self) -> &U {
+        self.base.as_ref()
+    }
+}
+
+impl<'data, T> fmt::Debug for NSMutableArray<'data, T>
+where
+    T: ObjectType<'data> + fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.base.fmt(f)
+    }
+}
+
+impl<'data, T: ObjectType<'data>> Default for Arc<NSMutableArray<'data, T>> {
+    #[inline]
+    fn default() -> Self {
+        unsafe { <NSMutableArray<T> as ClassType>::class().alloc_init() }
+    }
+}
+
+/// Creating mutable arrays.
+impl<'data, T: ObjectType<'data>> NSMutableArray<'data, T> {
+    /// Creates an empty mutable array.
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        Arc::default()
+    }
+
+    /// Creates a mutable array containing the objects in a slice.
+    ///
+    /// Each object is retained by the returned array.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/1460068-initwithobjects).
+    #[inline]
+    #[doc(alias = "initWithObjects:count:")]
+    pub fn from_slice(objects: &[Arc<T>]) -> Arc<Self> {
+        let objects = Arc::slice_as_ptr_array(objects);
+
+        unsafe {
+            let value: Arc<Self> = <Self as ClassType>::class().alloc();
+
+            // The initializer consumes the allocated instance.
+            let value = &*Arc::into_raw(value);
+
+            _msg_send_strict![
+                value,
+                initWithObjects: objects.as_ptr()
+                count: objects.len() as NSUInteger
+                => Arc<Self>
+            ]
+        }
+    }
+}
+
+/// Adding and removing objects.
+impl<'data, T: ObjectType<'data>> NSMutableArray<'data, T> {
+    /// Inserts `object` at the end of this array.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutablearray/1411695-addobject).
+    #[inline]
+    #[doc(alias = "addObject")]
+    #[doc(alias = "addObject:")]
+    pub fn add_object(&self, object: &T) {
+        unsafe { _msg_send_strict![self, addObject: object => ()] }
+    }
+}
+
+/// Rearranging content.
+impl<'data, T: ObjectType<'data>> NSMutableArray<'data, T> {
+    /// Sorts this array in place using the ordering returned by `compare`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutablearray/1415124-sortusingcomparator).
+    ///
+    /// # Safety
+    ///
+    /// It is safe to panic within the `compare` function. Panics will abort
+    /// the process.
+    #[inline]
+    #[doc(alias = "sortUsingComparator")]
+    #[doc(alias = "sortUsingComparator:")]
+    pub fn sort_using<F>(&self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        extern "C" fn invoke<T, F>(block: *mut StackBlock<F>, a: &T, b: &T) -> NSComparisonResult
+        where
+            F: FnMut(&T, &T) -> Ordering,
+        {
+            // SAFETY: The block is only called during `sortUsingComparator:`.
+            let compare = unsafe { StackBlock::closure(block) };
+
+            match panic::catch_unwind(panic::AssertUnwindSafe(|| compare(a, b))) {
+                Ok(ordering) => ordering.into(),
+                Err(_error) => process::abort(),
+            }
+        }
+
+        let invoke: extern "C" fn(_, &T, &T) -> _ = invoke::<T, F>;
+
+        // SAFETY: `invoke` matches `NSComparator`, which takes two objects and
+        // returns `NSComparisonResult`.
+        let mut block = unsafe { StackBlock::new(compare, invoke as *const c_void) };
+
+        unsafe { _msg_send_strict![self, sortUsingComparator: &mut block => ()] }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::foundation::NSNumber;
+
+    #[test]
+    fn sort_using() {
+        let numbers = [3, 1, 4, 1, 5].map(NSNumber::from_int);
+        let array = NSMutableArray::from_slice(&numbers);
+
+        array.sort_using(|a, b| b.cmp(a));
+
+        let sorted = array.map_to_vec(|n| n.int_value());
+        assert_eq!(sorted, [5, 4, 3, 1, 1]);
+    }
+}
@@ -0,0 +1,34 @@
+/// How an [`NSByteCountFormatter`](super::NSByteCountFormatter) counts
+/// bytes.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsbytecountformattercountstyle).
+#[repr(isize)] // NSInteger
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum NSByteCountFormatterCountStyle {
+    /// The platform's style for file sizes.
+    ///
+    /// This is [`Decimal`](Self::Decimal) on macOS and iOS.
+    #[doc(alias = "NSByteCountFormatterCountStyleFile")]
+    File = 0,
+
+    /// The platform's style for memory sizes.
+    ///
+    /// This is [`Binary`](Self::Binary) on macOS and iOS.
+    #[doc(alias = "NSByteCountFormatterCountStyleMemory")]
+    Memory = 1,
+
+    /// 1000 bytes are shown as 1 KB.
+    #[doc(alias = "NSByteCountFormatterCountStyleDecimal")]
+    Decimal = 2,
+
+    /// 1024 bytes are shown as 1 KB.
+    #[doc(alias = "NSByteCountFormatterCountStyleBinary")]
+    Binary = 3,
+}
+
+impl Default for NSByteCountFormatterCountStyle {
+    #[inline]
+    fn default() -> Self {
+        Self::File
+    }
+}
@@ -0,0 +1,143 @@
+use super::{NSFormatter, NSString};
+use crate::core::Arc;
+use crate::objc::ClassType;
+
+mod count_style;
+mod units;
+
+pub use count_style::*;
+pub use units::*;
+
+objc_subclass! {
+    /// A formatter that converts a byte count into a human-readable string,
+    /// such as `1.5 MB`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbytecountformatter).
+    pub class NSByteCountFormatter: NSFormatter;
+}
+
+impl Default for Arc<NSByteCountFormatter> {
+    #[inline]
+    fn default() -> Self {
+        NSByteCountFormatter::new()
+    }
+}
+
+impl NSByteCountFormatter {
+    /// Creates a formatter with the default units and count style.
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        Self::alloc().init()
+    }
+
+    /// Returns `count` formatted with `style`, using a shared formatter.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbytecountformatter/1415222-stringfrombytecount).
+    #[inline]
+    #[doc(alias = "stringFromByteCount:countStyle:")]
+    pub fn string_from_byte_count_with_style(
+        count: i64,
+        style: NSByteCountFormatterCountStyle,
+    ) -> Arc<NSString<'static>> {
+        let string: &NSString = unsafe {
+            _msg_send_any![
+                <Self as ClassType>::class(),
+                stringFromByteCount: count
+                countStyle: style
+            ]
+        };
+        Arc::retain(string)
+    }
+
+    /// Returns `count` formatted with the settings of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbytecountformatter/1410471-stringfrombytecount).
+    #[inline]
+    #[doc(alias = "stringFromByteCount")]
+    #[doc(alias = "stringFromByteCount:")]
+    pub fn string_from_byte_count(&self, count: i64) -> Arc<NSString<'static>> {
+        let string: &NSString = unsafe { _msg_send_any![self, stringFromByteCount: count] };
+        Arc::retain(string)
+    }
+}
+
+/// Formatter settings.
+impl NSByteCountFormatter {
+    /// Returns the units that this formatter may use.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbytecountformatter/1414453-allowedunits).
+    #[inline]
+    #[doc(alias = "allowedUnits")]
+    pub fn allowed_units(&self) -> NSByteCountFormatterUnits {
+        unsafe { _msg_send_any![self, allowedUnits] }
+    }
+
+    /// Sets the units that this formatter may use.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbytecountformatter/1414453-allowedunits).
+    #[inline]
+    #[doc(alias = "setAllowedUnits")]
+    #[doc(alias = "setAllowedUnits:")]
+    pub fn set_allowed_units(&self, units: NSByteCountFormatterUnits) {
+        unsafe { _msg_send_any![self, setAllowedUnits: units] }
+    }
+
+    /// Returns how this formatter counts bytes.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbytecountformatter/1415139-countstyle).
+    #[inline]
+    #[doc(alias = "countStyle")]
+    pub fn count_style(&self) -> NSByteCountFormatterCountStyle {
+        unsafe { _msg_send_any![self, countStyle] }
+    }
+
+    /// Sets how this formatter counts bytes.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbytecountformatter/1415139-countstyle).
+    #[inline]
+    #[doc(alias = "setCountStyle")]
+    #[doc(alias = "setCountStyle:")]
+    pub fn set_count_style(&self, style: NSByteCountFormatterCountStyle) {
+        unsafe { _msg_send_any![self, setCountStyle: style] }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn string_from_byte_count() {
+        let formatter = NSByteCountFormatter::new();
+        assert_eq!(
+            formatter.allowed_units(),
+            NSByteCountFormatterUnits::DEFAULT
+        );
+        assert_eq!(
+            formatter.count_style(),
+            NSByteCountFormatterCountStyle::File
+        );
+
+        let string = formatter.string_from_byte_count(1_500_000);
+        assert!(string.to_string().contains("MB"), "{}", string);
+
+        formatter.set_allowed_units(NSByteCountFormatterUnits::KB);
+        assert_eq!(formatter.allowed_units(), NSByteCountFormatterUnits::KB);
+
+        let string = formatter.string_from_byte_count(1_500_000);
+        assert!(string.to_string().contains("KB"), "{}", string);
+
+        formatter.set_allowed_units(NSByteCountFormatterUnits::DEFAULT);
+        formatter.set_count_style(NSByteCountFormatterCountStyle::Binary);
+        assert_eq!(
+            formatter.count_style(),
+            NSByteCountFormatterCountStyle::Binary
+        );
+
+        let string = NSByteCountFormatter::string_from_byte_count_with_style(
+            1 << 20,
+            NSByteCountFormatterCountStyle::Binary,
+        );
+        assert!(*string == *formatter.string_from_byte_count(1 << 20));
+    }
+}
@@ -0,0 +1,55 @@
+options! {
+    /// Units that an [`NSByteCountFormatter`](super::NSByteCountFormatter) is
+    /// allowed to use.
+    ///
+    /// Units can be combined using `|`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbytecountformatterunits).
+    pub struct NSByteCountFormatterUnits;
+}
+
+impl NSByteCountFormatterUnits {
+    /// The units appropriate for the count style.
+    #[doc(alias = "NSByteCountFormatterUseDefault")]
+    pub const DEFAULT: Self = Self(0);
+
+    /// Bytes.
+    #[doc(alias = "NSByteCountFormatterUseBytes")]
+    pub const BYTES: Self = Self(1 << 0);
+
+    /// Kilobytes.
+    #[doc(alias = "NSByteCountFormatterUseKB")]
+    pub const KB: Self = Self(1 << 1);
+
+    /// Megabytes.
+    #[doc(alias = "NSByteCountFormatterUseMB")]
+    pub const MB: Self = Self(1 << 2);
+
+    /// Gigabytes.
+    #[doc(alias = "NSByteCountFormatterUseGB")]
+    pub const GB: Self = Self(1 << 3);
+
+    /// Terabytes.
+    #[doc(alias = "NSByteCountFormatterUseTB")]
+    pub const TB: Self = Self(1 << 4);
+
+    /// Petabytes.
+    #[doc(alias = "NSByteCountFormatterUsePB")]
+    pub const PB: Self = Self(1 << 5);
+
+    /// Exabytes.
+    #[doc(alias = "NSByteCountFormatterUseEB")]
+    pub const EB: Self = Self(1 << 6);
+
+    /// Zettabytes.
+    #[doc(alias = "NSByteCountFormatterUseZB")]
+    pub const ZB: Self = Self(1 << 7);
+
+    /// Yottabytes or higher.
+    #[doc(alias = "NSByteCountFormatterUseYBOrHigher")]
+    pub const YB_OR_HIGHER: Self = Self(0x0FF << 8);
+
+    /// All units.
+    #[doc(alias = "NSByteCountFormatterUseAll")]
+    pub const ALL: Self = Self(0x0FFFF);
+}
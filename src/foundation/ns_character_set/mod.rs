@@ -0,0 +1,269 @@
+use super::{NSData, NSString};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, BOOL};
+use std::fmt;
+
+objc_subclass! {
+    /// A static set of Unicode character values for use in search operations.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset).
+    pub class NSCharacterSet: NSObject<'static>;
+}
+
+/// Convenience macro to reduce boilerplate for standard character sets.
+macro_rules! standard_sets {
+    ($(
+        $(#[$meta:meta])+
+        $name:ident = $sel:ident;
+    )+) => {
+        /// Standard character sets.
+        impl NSCharacterSet {
+            $(
+                $(#[$meta])+
+                #[inline]
+                pub fn $name() -> Arc<Self> {
+                    let set: &Self = unsafe { _msg_send_any![Self::class(), $sel] };
+                    Arc::retain(set)
+                }
+            )+
+        }
+    };
+}
+
+standard_sets! {
+    /// A character set containing the characters in Unicode General Category
+    /// Zs and `CHARACTER TABULATION (U+0009)`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/1416086-whitespacecharacterset).
+    whitespace = whitespaceCharacterSet;
+
+    /// A character set containing characters in Unicode General Category Z*,
+    /// `U+000A ~ U+000D`, and `U+0085`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/1414329-whitespaceandnewlinecharacterset).
+    whitespace_and_newline = whitespaceAndNewlineCharacterSet;
+
+    /// A character set containing the newline characters (`U+000A ~ U+000D`,
+    /// `U+0085`, `U+2028`, and `U+2029`).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/1416730-newlinecharacterset).
+    newline = newlineCharacterSet;
+
+    /// A character set containing the characters in the categories Letters,
+    /// Marks, and Numbers.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/1411276-alphanumericcharacterset).
+    alphanumeric = alphanumericCharacterSet;
+
+    /// A character set containing the characters in Unicode General Category L*
+    /// & M*.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/1413079-lettercharacterset).
+    letters = letterCharacterSet;
+
+    /// A character set containing the characters in the category of Decimal
+    /// Numbers.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/1408239-decimaldigitcharacterset).
+    decimal_digits = decimalDigitCharacterSet;
+
+    /// A character set containing the characters in Unicode General Category
+    /// P*.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/1416734-punctuationcharacterset).
+    punctuation = punctuationCharacterSet;
+
+    /// A character set containing the characters in Unicode General Category
+    /// Lu and Lt.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/1408307-uppercaselettercharacterset).
+    uppercase_letters = uppercaseLetterCharacterSet;
+
+    /// A character set containing the characters in Unicode General Category
+    /// Ll.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/1417719-lowercaselettercharacterset).
+    lowercase_letters = lowercaseLetterCharacterSet;
+}
+
+impl NSCharacterSet {
+    /// Creates a character set containing the characters in `string`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/1417539-charactersetwithcharactersinstri).
+    #[inline]
+    #[doc(alias = "characterSetWithCharactersInString")]
+    #[doc(alias = "characterSetWithCharactersInString:")]
+    pub fn from_characters_in_string(string: &NSString) -> Arc<Self> {
+        let set: &Self =
+            unsafe { _msg_send_any![Self::class(), characterSetWithCharactersInString: string] };
+        Arc::retain(set)
+    }
+
+    /// Returns `true` if `c` is in this character set.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/1416011-longcharacterismember).
+    #[inline]
+    #[doc(alias = "longCharacterIsMember")]
+    #[doc(alias = "longCharacterIsMember:")]
+    pub fn contains(&self, c: char) -> bool {
+        unsafe { _msg_send_any![self, longCharacterIsMember: c as u32 => BOOL] }.into()
+    }
+
+    /// Returns `true` if the UTF-16 code unit `c` is in this character set.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/1412732-characterismember).
+    #[inline]
+    #[doc(alias = "characterIsMember")]
+    #[doc(alias = "characterIsMember:")]
+    pub fn character_is_member(&self, c: u16) -> bool {
+        unsafe { _msg_send_any![self, characterIsMember: c => BOOL] }.into()
+    }
+
+    /// Returns a data object encoding the membership of this character set as
+    /// a bitmap.
+    ///
+    /// Use [`CharacterSetBitmap`] to query the bitmap efficiently.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/1417564-bitmaprepresentation).
+    #[inline]
+    #[doc(alias = "bitmapRepresentation")]
+    pub fn bitmap_representation(&self) -> Arc<NSData> {
+        let data: &NSData = unsafe { _msg_send_any![self, bitmapRepresentation] };
+        Arc::retain(data)
+    }
+}
+
+/// The number of bytes needed to represent the membership of a Unicode plane.
+const PLANE_SIZE: usize = 8192;
+
+/// The number of Unicode planes.
+const PLANE_COUNT: usize = 17;
+
+/// A Rust-side copy of the
+/// [bitmap representation](NSCharacterSet::bitmap_representation) of an
+/// [`NSCharacterSet`].
+///
+/// This answers membership queries without messaging Objective-C, which makes
+/// it suitable for hot paths.
+#[derive(Clone, Default)]
+pub struct CharacterSetBitmap {
+    // Planes without any members are `None`.
+    planes: [Option<Box<[u8; PLANE_SIZE]>>; PLANE_COUNT],
+}
+
+impl fmt::Debug for CharacterSetBitmap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let planes = (0..PLANE_COUNT).filter(|&plane| self.planes[plane].is_some());
+        f.debug_struct("CharacterSetBitmap")
+            .field("planes", &planes.collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl From<&NSCharacterSet> for CharacterSetBitmap {
+    #[inline]
+    fn from(set: &NSCharacterSet) -> Self {
+        Self::new(set)
+    }
+}
+
+impl CharacterSetBitmap {
+    /// Creates a bitmap from the bitmap representation of `set`.
+    #[inline]
+    pub fn new(set: &NSCharacterSet) -> Self {
+        let data = set.bitmap_representation();
+
+        // SAFETY: The data object is immutable.
+        Self::from_bytes(unsafe { data.as_slice() })
+    }
+
+    /// Creates a bitmap from the raw bytes of a character set's bitmap
+    /// representation.
+    ///
+    /// The first 8192 bytes are the bitmap for the Basic Multilingual Plane.
+    /// Each following plane is stored as its index byte followed by 8192 bytes.
+    /// Invalid or truncated planes are ignored.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut bitmap = Self::default();
+
+        let (bmp, mut rest) = bytes.split_at(bytes.len().min(PLANE_SIZE));
+        if bmp.iter().any(|&byte| byte != 0) {
+            let mut plane = Box::new([0; PLANE_SIZE]);
+            plane[..bmp.len()].copy_from_slice(bmp);
+            bitmap.planes[0] = Some(plane);
+        }
+
+        while let Some((&index, after_index)) = rest.split_first() {
+            if after_index.len() < PLANE_SIZE {
+                break;
+            }
+            let (plane, after_plane) = after_index.split_at(PLANE_SIZE);
+            rest = after_plane;
+
+            let index = index as usize;
+            if index == 0 || index >= PLANE_COUNT {
+                continue;
+            }
+
+            let mut copy = Box::new([0; PLANE_SIZE]);
+            copy.copy_from_slice(plane);
+            bitmap.planes[index] = Some(copy);
+        }
+
+        bitmap
+    }
+
+    /// Returns `true` if `c` is in this character set.
+    #[inline]
+    pub fn contains(&self, c: char) -> bool {
+        let c = c as u32;
+        let plane = (c >> 16) as usize;
+        let index = (c & 0xFFFF) as usize;
+
+        match &self.planes[plane] {
+            Some(bits) => bits[index >> 3] & (1 << (index & 7)) != 0,
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_bytes() {
+        let mut bytes = vec![0; PLANE_SIZE];
+        bytes[(b'a' >> 3) as usize] |= 1 << (b'a' & 7);
+
+        // U+1F980 CRAB
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; PLANE_SIZE]);
+        bytes[PLANE_SIZE + 1 + (0xF980 >> 3)] |= 1 << (0xF980 & 7);
+
+        let bitmap = CharacterSetBitmap::from_bytes(&bytes);
+        assert!(bitmap.contains('a'));
+        assert!(!bitmap.contains('b'));
+        assert!(bitmap.contains('🦀'));
+        assert!(!bitmap.contains('🦁'));
+    }
+
+    #[test]
+    fn matches_character_set() {
+        let sets = [
+            NSCharacterSet::whitespace_and_newline(),
+            NSCharacterSet::alphanumeric(),
+            NSCharacterSet::punctuation(),
+            NSCharacterSet::from_characters_in_string(ns_string!("a🦀")),
+        ];
+
+        let sample = ('\0'..='\u{2FFF}').step_by(7).chain(['🦀', '🦁', '\u{10FFFF}']);
+
+        for set in &sets {
+            let bitmap = CharacterSetBitmap::new(set);
+            for c in sample.clone() {
+                assert_eq!(bitmap.contains(c), set.contains(c), "{:?}", c);
+            }
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use super::{error_codes, NSError, NSRange, NSString};
+use crate::core::Arc;
+use crate::objc::{ClassType, Copying, NSObject, NSUInteger, BOOL};
+use std::{
+    ffi::c_void,
+    hash::{Hash, Hasher},
+    slice,
+};
+
+mod search_options;
+
+pub use search_options::*;
+
+objc_subclass! {
+    /// A static byte buffer in memory.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdata).
+    pub class NSData: NSObject<'static>;
+}
+
+// SAFETY: Copying data returns an immutable data object.
+unsafe impl Copying<'static> for NSData {}
+
+impl PartialEq for NSData {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { _msg_send_any![self, isEqualToData: other => BOOL] }.into()
+    }
+}
+
+impl Eq for NSData {}
+
+impl PartialEq<[u8]> for NSData {
+    #[inline]
+    fn eq(&self, other: &[u8]) -> bool {
+        // SAFETY: The slice does not outlive this comparison.
+        unsafe { self.as_slice() == other }
+    }
+}
+
+impl PartialEq<NSData> for [u8] {
+    #[inline]
+    fn eq(&self, other: &NSData) -> bool {
+        other == self
+    }
+}
+
+impl Hash for NSData {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        NSObject::hash(self).hash(state);
+    }
+}
+
+impl From<&[u8]> for Arc<NSData> {
+    #[inline]
+    fn from(bytes: &[u8]) -> Self {
+        NSData::from_bytes(bytes)
+    }
+}
+
+impl NSData {
+    /// Creates a data object containing a copy of `bytes`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdata/1412793-initwithbytes).
+    #[inline]
+    #[doc(alias = "initWithBytes")]
+    #[doc(alias = "initWithBytes:length:")]
+    pub fn from_bytes(bytes: &[u8]) -> Arc<Self> {
+        unsafe {
+            let value: Arc<Self> = Self::class().alloc();
+            let value = &*Arc::into_raw(value);
+            _msg_send_any![
+                value,
+                initWithBytes: bytes.as_ptr().cast::<c_void>()
+                length: bytes.len() as NSUInteger
+            ]
+        }
+    }
+
+    /// Returns the number of bytes contained by this data object.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdata/1416769-length).
+    #[inline]
+    pub fn length(&self) -> NSUInteger {
+        unsafe { _msg_send_any![self, length] }
+    }
+
+    /// Returns `true` if this data object contains no bytes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.length() == 0
+    }
+
+    /// Returns a pointer to the contents of this data object.
+    ///
+    /// This may be null if [`length`](Self::length) is 0.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdata/1410616-bytes).
+    #[inline]
+    #[doc(alias = "bytes")]
+    pub fn as_ptr(&self) -> *const u8 {
+        unsafe { _msg_send_any![self, bytes => *const c_void] }.cast()
+    }
+
+    /// Returns the contents of this data object as a byte slice.
+    ///
+    /// # Safety
+    ///
+    /// You must ensure that `self` is not mutated during the lifetime of the
+    /// returned slice.
+    #[inline]
+    pub unsafe fn as_slice(&self) -> &[u8] {
+        let len = self.length();
+        if len == 0 {
+            return &[];
+        }
+        slice::from_raw_parts(self.as_ptr(), len)
+    }
+
+    /// Returns a copy of the contents of this data object.
+    #[inline]
+    pub fn to_vec(&self) -> Vec<u8> {
+        // SAFETY: The slice is immediately copied.
+        unsafe { self.as_slice() }.to_vec()
+    }
+}
+
+/// Searching and slicing data.
+impl NSData {
+    /// Returns a data object containing a copy of the bytes of `self` that
+    /// lie within `range`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdata/1411542-subdatawithrange).
+    ///
+    /// # Exception Handling
+    ///
+    /// If `range` extends beyond the end of `self`, an `NSRangeException` is
+    /// thrown.
+    #[inline]
+    #[doc(alias = "subdataWithRange")]
+    #[doc(alias = "subdataWithRange:")]
+    pub fn subdata_with_range(&self, range: NSRange) -> Arc<Self> {
+        let data: &Self = unsafe { _msg_send_any![self, subdataWithRange: range] };
+        Arc::retain(data)
+    }
+
+    /// Returns the range of the first occurrence of `needle` within `range`,
+    /// or `None` if it is not found.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdata/1410391-rangeofdata).
+    ///
+    /// # Exception Handling
+    ///
+    /// If `range` extends beyond the end of `self`, an `NSRangeException` is
+    /// thrown.
+    #[inline]
+    #[doc(alias = "rangeOfData")]
+    #[doc(alias = "rangeOfData:options:range:")]
+    pub fn range_of_data(
+        &self,
+        needle: &NSData,
+        options: NSDataSearchOptions,
+        range: NSRange,
+    ) -> Option<NSRange> {
+        let found: NSRange =
+            unsafe { _msg_send_any![self, rangeOfData: needle options: options range: range] };
+        found.found()
+    }
+}
+
+/// Reading and writing data.
+impl NSData {
+    /// Creates a data object by reading the contents of the file at `path`.
+    ///
+    /// The file is memory-mapped if safe to do so.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdata/1411145-initwithcontentsoffile).
+    #[inline]
+    #[doc(alias = "initWithContentsOfFile")]
+    #[doc(alias = "initWithContentsOfFile:options:error:")]
+    pub fn from_contents_of_file(path: &NSString) -> Result<Arc<Self>, Arc<NSError<'static>>> {
+        // `NSDataReadingMappedIfSafe`
+        const MAPPED_IF_SAFE: NSUInteger = 1 << 0;
+
+        NSError::try_with(error_codes::NSFileReadUnknownError, |error| unsafe {
+            let value: Arc<Self> = Self::class().alloc();
+
+            // The initializer consumes the allocated instance.
+            let value = &*Arc::into_raw(value);
+
+            _msg_send_strict![
+                value,
+                initWithContentsOfFile: path
+                options: MAPPED_IF_SAFE
+                error: error
+                => Option<Arc<Self>>
+            ]
+        })
+    }
+
+    /// Writes the bytes of this data object to the file at `path`.
+    ///
+    /// If `atomically` is `true`, the data is first written to an auxiliary
+    /// file that then replaces the original file.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdata/1414800-writetofile).
+    #[inline]
+    #[doc(alias = "writeToFile")]
+    #[doc(alias = "writeToFile:options:error:")]
+    pub fn write_to_file(
+        &self,
+        path: &NSString,
+        atomically: bool,
+    ) -> Result<(), Arc<NSError<'static>>> {
+        // `NSDataWritingAtomic`
+        const ATOMIC: NSUInteger = 1 << 0;
+
+        let options = if atomically { ATOMIC } else { 0 };
+
+        NSError::try_with(error_codes::NSFileWriteUnknownError, |error| {
+            let success: BOOL =
+                unsafe { _msg_send_strict![self, writeToFile: path options: options error: error] };
+            if success.into() {
+                Some(())
+            } else {
+                None
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_io() {
+        let path = std::env::temp_dir().join("fruity_ns_data_file_io.bin");
+        let path_string = NSString::from_str(path.to_str().unwrap());
+
+        let bytes: Vec<u8> = (0..=255).collect();
+        NSData::from_bytes(&bytes)
+            .write_to_file(&path_string, true)
+            .unwrap_or_else(|error| panic!("{}", error));
+
+        let read =
+            NSData::from_contents_of_file(&path_string).unwrap_or_else(|error| panic!("{}", error));
+        assert_eq!(read.to_vec(), bytes);
+
+        std::fs::remove_file(path).unwrap();
+
+        assert!(NSData::from_contents_of_file(&path_string).is_err());
+    }
+
+    #[test]
+    fn search() {
+        let data = NSData::from_bytes(b"\x00\xCA\xFE\x00\xCA\xFE");
+        let needle = NSData::from_bytes(b"\xCA\xFE");
+        let whole = NSRange::new(0, data.length());
+
+        let found = data.range_of_data(&needle, NSDataSearchOptions::NONE, whole);
+        assert_eq!(found, Some(NSRange::new(1, 2)));
+
+        let subdata = data.subdata_with_range(found.unwrap());
+        assert!(*subdata == *needle);
+
+        let found = data.range_of_data(&needle, NSDataSearchOptions::BACKWARDS, whole);
+        assert_eq!(found, Some(NSRange::new(4, 2)));
+
+        let found = data.range_of_data(&needle, NSDataSearchOptions::ANCHORED, whole);
+        assert_eq!(found, None);
+
+        assert!(data.subdata_with_range(NSRange::new(0, 0)).is_empty());
+    }
+
+    #[test]
+    fn eq_hash() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash(data: &NSData) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            data.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let a = NSData::from_bytes(b"fruity");
+        let b = NSData::from_bytes(b"fruity");
+        let c = NSData::from_bytes(b"apple");
+
+        assert!(*a == *b);
+        assert_eq!(hash(&a), hash(&b));
+        assert!(*a != *c);
+
+        assert!(*a == b"fruity"[..]);
+        assert!(*a != b"fruit"[..]);
+        assert!(b"fruity"[..] == *a);
+    }
+}
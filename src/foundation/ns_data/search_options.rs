@@ -0,0 +1,20 @@
+options! {
+    /// Options for searching [`NSData`](super::NSData) instances.
+    ///
+    /// Options can be combined using `|`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdatasearchoptions).
+    pub struct NSDataSearchOptions;
+}
+
+impl NSDataSearchOptions {
+    /// No options.
+    pub const NONE: Self = Self(0);
+
+    /// Search from the end of the data object.
+    pub const BACKWARDS: Self = Self(1 << 0);
+
+    /// Search is limited to the start (or end, if
+    /// [`BACKWARDS`](#associatedconstant.BACKWARDS)) of the data object.
+    pub const ANCHORED: Self = Self(1 << 1);
+}
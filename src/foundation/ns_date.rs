@@ -0,0 +1,132 @@
+use super::{NSComparisonResult, NSTimeInterval};
+use crate::core::{Arc, ThreadSafe};
+use crate::objc::{ClassType, Copying, NSObject, BOOL};
+use std::cmp::Ordering;
+
+objc_subclass! {
+    /// A specific point in time, independent of any calendar or time zone.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdate).
+    pub class NSDate: NSObject<'static>;
+}
+
+// SAFETY: Dates are immutable.
+unsafe impl ThreadSafe for NSDate {}
+
+// SAFETY: Dates are immutable, so copying returns the same date.
+unsafe impl Copying<'static> for NSDate {}
+
+impl PartialEq for NSDate {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { _msg_send_any![self, isEqualToDate: other => BOOL] }.into()
+    }
+}
+
+impl PartialOrd for NSDate {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.compare(other).into())
+    }
+}
+
+impl NSDate {
+    /// Creates a date for the current date and time.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdate/1409599-date).
+    #[inline]
+    #[doc(alias = "date")]
+    pub fn now() -> Arc<Self> {
+        Self::alloc().init()
+    }
+
+    /// Creates a date that is `interval` seconds from 00:00:00 UTC on
+    /// 1 January 2001.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdate/1410603-initwithtimeintervalsincereferenc).
+    #[inline]
+    #[doc(alias = "initWithTimeIntervalSinceReferenceDate")]
+    #[doc(alias = "initWithTimeIntervalSinceReferenceDate:")]
+    pub fn from_time_interval_since_reference_date(interval: NSTimeInterval) -> Arc<Self> {
+        unsafe {
+            <Self as ClassType>::alloc_init_with(
+                selector!(initWithTimeIntervalSinceReferenceDate:),
+                (interval,),
+            )
+        }
+    }
+
+    /// Creates a date that is `interval` seconds from 00:00:00 UTC on
+    /// 1 January 1970.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdate/1408795-initwithtimeintervalsince1970).
+    #[inline]
+    #[doc(alias = "initWithTimeIntervalSince1970")]
+    #[doc(alias = "initWithTimeIntervalSince1970:")]
+    pub fn from_time_interval_since_1970(interval: NSTimeInterval) -> Arc<Self> {
+        unsafe {
+            <Self as ClassType>::alloc_init_with(
+                selector!(initWithTimeIntervalSince1970:),
+                (interval,),
+            )
+        }
+    }
+
+    /// Returns the number of seconds between `self` and 00:00:00 UTC on
+    /// 1 January 2001.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdate/1387556-timeintervalsincereferencedate).
+    #[inline]
+    #[doc(alias = "timeIntervalSinceReferenceDate")]
+    pub fn time_interval_since_reference_date(&self) -> NSTimeInterval {
+        unsafe { _msg_send_any![self, timeIntervalSinceReferenceDate] }
+    }
+
+    /// Returns the number of seconds between `self` and 00:00:00 UTC on
+    /// 1 January 1970.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdate/1407504-timeintervalsince1970).
+    #[inline]
+    #[doc(alias = "timeIntervalSince1970")]
+    pub fn time_interval_since_1970(&self) -> NSTimeInterval {
+        unsafe { _msg_send_any![self, timeIntervalSince1970] }
+    }
+
+    /// Returns the number of seconds between `self` and `other`, which is
+    /// negative if `self` is earlier.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdate/1413447-timeintervalsincedate).
+    #[inline]
+    #[doc(alias = "timeIntervalSinceDate")]
+    #[doc(alias = "timeIntervalSinceDate:")]
+    pub fn time_interval_since(&self, other: &NSDate) -> NSTimeInterval {
+        unsafe { _msg_send_any![self, timeIntervalSinceDate: other] }
+    }
+
+    /// Returns the temporal ordering of `self` and `other`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdate/1410701-compare).
+    #[inline]
+    pub fn compare(&self, other: &NSDate) -> NSComparisonResult {
+        unsafe { _msg_send_any![self, compare: other] }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_intervals() {
+        let epoch = NSDate::from_time_interval_since_1970(0.0);
+        assert_eq!(epoch.time_interval_since_1970(), 0.0);
+        assert_eq!(epoch.time_interval_since_reference_date(), -978_307_200.0);
+
+        let reference = NSDate::from_time_interval_since_reference_date(0.0);
+        assert_eq!(reference.time_interval_since(&epoch), 978_307_200.0);
+        assert!(*epoch < *reference);
+        assert!(*epoch == *NSDate::from_time_interval_since_reference_date(-978_307_200.0));
+
+        assert!(*NSDate::now() > *reference);
+    }
+}
@@ -0,0 +1,137 @@
+use super::{NSDate, NSFormatter, NSLocale, NSString};
+use crate::core::Arc;
+use crate::objc::ClassType;
+
+objc_subclass! {
+    /// A formatter that converts between dates and their textual
+    /// representations.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdateformatter).
+    pub class NSDateFormatter: NSFormatter;
+}
+
+impl Default for Arc<NSDateFormatter> {
+    #[inline]
+    fn default() -> Self {
+        NSDateFormatter::new()
+    }
+}
+
+impl NSDateFormatter {
+    /// Creates a formatter for the current locale and time zone.
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        Self::alloc().init()
+    }
+
+    /// Returns `date` formatted with the settings of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdateformatter/1418102-stringfromdate).
+    #[inline]
+    #[doc(alias = "stringFromDate")]
+    #[doc(alias = "stringFromDate:")]
+    pub fn string_from_date(&self, date: &NSDate) -> Arc<NSString<'static>> {
+        let string: &NSString = unsafe { _msg_send_any![self, stringFromDate: date] };
+        Arc::retain(string)
+    }
+
+    /// Returns the date parsed from `string` with the settings of `self`, or
+    /// `None` if it could not be parsed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdateformatter/1413018-datefromstring).
+    #[inline]
+    #[doc(alias = "dateFromString")]
+    #[doc(alias = "dateFromString:")]
+    pub fn date_from_string(&self, string: &NSString) -> Option<Arc<NSDate>> {
+        let date: Option<&NSDate> = unsafe { _msg_send_any![self, dateFromString: string] };
+        date.map(Arc::retain)
+    }
+}
+
+/// Formatter settings.
+impl NSDateFormatter {
+    /// Returns the [format pattern] used by this formatter.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdateformatter/1413514-dateformat).
+    ///
+    /// [format pattern]: https://unicode.org/reports/tr35/tr35-dates.html#Date_Format_Patterns
+    #[inline]
+    #[doc(alias = "dateFormat")]
+    pub fn date_format(&self) -> Arc<NSString<'static>> {
+        let format: &NSString = unsafe { _msg_send_any![self, dateFormat] };
+        Arc::retain(format)
+    }
+
+    /// Sets the [format pattern], such as `"yyyy-MM-dd"`, used by this
+    /// formatter.
+    ///
+    /// For fixed formats, also [set the locale](Self::set_locale) to
+    /// `en_US_POSIX` so that the user's settings do not affect the result.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdateformatter/1413514-dateformat).
+    ///
+    /// [format pattern]: https://unicode.org/reports/tr35/tr35-dates.html#Date_Format_Patterns
+    #[inline]
+    #[doc(alias = "setDateFormat")]
+    #[doc(alias = "setDateFormat:")]
+    pub fn set_date_format(&self, format: &NSString) {
+        unsafe { _msg_send_any![self, setDateFormat: format] }
+    }
+
+    /// Returns the locale used by this formatter.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdateformatter/1411973-locale).
+    #[inline]
+    pub fn locale(&self) -> Arc<NSLocale> {
+        let locale: &NSLocale = unsafe { _msg_send_any![self, locale] };
+        Arc::retain(locale)
+    }
+
+    /// Sets the locale used by this formatter.
+    ///
+    /// If `locale` is `None`, the current locale is used.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdateformatter/1411973-locale).
+    #[inline]
+    #[doc(alias = "setLocale")]
+    #[doc(alias = "setLocale:")]
+    pub fn set_locale(&self, locale: Option<&NSLocale>) {
+        unsafe { _msg_send_any![self, setLocale: locale] }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn posix_formatter(format: &NSString) -> Arc<NSDateFormatter> {
+        let formatter = NSDateFormatter::new();
+        formatter.set_locale(Some(&NSLocale::from_identifier(ns_string!("en_US_POSIX"))));
+        formatter.set_date_format(format);
+        formatter
+    }
+
+    #[test]
+    fn round_trip() {
+        let formatter = posix_formatter(ns_string!("yyyy-MM-dd'T'HH:mm:ssXXXXX"));
+        assert_eq!(*formatter.date_format(), "yyyy-MM-dd'T'HH:mm:ssXXXXX");
+
+        let epoch = NSDate::from_time_interval_since_1970(0.0);
+        let parsed = formatter
+            .date_from_string(ns_string!("1970-01-01T00:00:00Z"))
+            .unwrap();
+        assert!(*parsed == *epoch);
+
+        // Dates without a time are parsed at midnight in the local time zone.
+        let formatter = posix_formatter(ns_string!("yyyy-MM-dd"));
+        let date = formatter
+            .date_from_string(ns_string!("2021-03-14"))
+            .unwrap();
+
+        let string = formatter.string_from_date(&date);
+        assert_eq!(*string, "2021-03-14");
+        assert!(*formatter.date_from_string(&string).unwrap() == *date);
+
+        assert!(formatter.date_from_string(ns_string!("March")).is_none());
+    }
+}
@@ -0,0 +1,205 @@
+use crate::core::Arc;
+use crate::objc::{Class, ClassType, Copying, NSObject, NSUInteger, ObjCObject, ObjectType, BOOL};
+use std::{marker::PhantomData, ops::Deref, ptr::NonNull};
+
+/// A static collection of objects associated with unique keys.
+///
+/// The type parameters `K` and `V` are the types of the keys and values stored
+/// in the dictionary. They default to [`ObjCObject`] for dictionaries whose
+/// contents are not statically known.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsdictionary).
+#[repr(C)]
+pub struct NSDictionary<'data, K = ObjCObject<'data>, V = ObjCObject<'data>> {
+    base: NSObject<'data>,
+    marker: PhantomData<(K, V)>,
+}
+
+impl<K, V> crate::core::ObjectType for NSDictionary<'_, K, V> {
+    #[inline]
+    fn retain(obj: &Self) -> Arc<Self> {
+        let obj = Arc::retain(&obj.base);
+        unsafe { Arc::cast_unchecked(obj) }
+    }
+
+    #[inline]
+    unsafe fn release(obj: NonNull<Self>) {
+        NSObject::release(obj.cast());
+    }
+}
+
+impl<'data, K: 'data, V: 'data> ObjectType<'data> for NSDictionary<'data, K, V> {}
+
+impl<'data, K: 'data, V: 'data> ClassType<'data> for NSDictionary<'data, K, V> {
+    #[inline]
+    unsafe fn direct_class() -> &'static Class {
+        crate::_objc_class!(@ "OBJC_CLASS_$_NSDictionary")
+    }
+}
+
+// SAFETY: Copying a dictionary returns an immutable dictionary of the same
+// entries.
+unsafe impl<'data, K: 'data, V: 'data> Copying<'data> for NSDictionary<'data, K, V> {}
+
+impl<'data, K, V> Deref for NSDictionary<'data, K, V> {
+    type Target = NSObject<'data>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'data, K, V> AsRef<NSDictionary<'data, K, V>> for NSDictionary<'data, K, V> {
+    #[inline]
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl<'data, K, V, U> AsRef<U> for NSDictionary<'data, K, V>
+where
+    NSObject<'data>: AsRef<U>,
+{
+    #[inline]
+    fn as_ref(&self) -> &U {
+        self.base.as_ref()
+    }
+}
+
+impl<'data, K, V> PartialEq for NSDictionary<'data, K, V>
+where
+    K: ObjectType<'data>,
+    V: ObjectType<'data>,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.is_equal_to_dictionary(other)
+    }
+}
+
+impl<'data, K, V> Eq for NSDictionary<'data, K, V>
+where
+    K: ObjectType<'data>,
+    V: ObjectType<'data>,
+{
+}
+
+impl<'data, K, V> NSDictionary<'data, K, V>
+where
+    K: ObjectType<'data>,
+    V: ObjectType<'data>,
+{
+    /// Creates a dictionary containing entries constructed from the
+    /// corresponding keys and objects.
+    ///
+    /// Each key is copied and each object is retained by the returned
+    /// dictionary.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `keys` and `objects` have different lengths.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdictionary/1410010-initwithobjects).
+    #[inline]
+    #[doc(alias = "initWithObjects:forKeys:count:")]
+    pub fn from_keys_and_objects(keys: &[Arc<K>], objects: &[Arc<V>]) -> Arc<Self> {
+        assert_eq!(
+            keys.len(),
+            objects.len(),
+            "keys and objects must have the same length"
+        );
+
+        let keys = Arc::slice_as_ptr_array(keys);
+        let objects = Arc::slice_as_ptr_array(objects);
+
+        unsafe {
+            let value: Arc<Self> = <Self as ClassType>::class().alloc();
+
+            // The initializer consumes the allocated instance.
+            let value = &*Arc::into_raw(value);
+
+            _msg_send_strict![
+                value,
+                initWithObjects: objects.as_ptr()
+                forKeys: keys.as_ptr()
+                count: keys.len() as NSUInteger
+                => Arc<Self>
+            ]
+        }
+    }
+
+    /// Returns the number of entries in this dictionary.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdictionary/1410780-count).
+    #[inline]
+    pub fn count(&self) -> usize {
+        unsafe { _msg_send_any![self, count => NSUInteger] }
+    }
+
+    /// Returns `true` if this dictionary contains no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.count() == 0
+    }
+
+    /// Returns the value associated with `key`, or `None` if no value is
+    /// associated with it.
+    ///
+    /// Keys are compared with `isEqual:`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdictionary/1414347-objectforkey).
+    #[inline]
+    #[doc(alias = "objectForKey")]
+    #[doc(alias = "objectForKey:")]
+    pub fn object_for_key(&self, key: &K) -> Option<Arc<V>> {
+        let object: Option<&V> = unsafe { _msg_send_strict![self, objectForKey: key] };
+        object.map(Arc::retain)
+    }
+
+    /// Returns `true` if the entries of `other` are equal to those of `self`.
+    ///
+    /// Two dictionaries have equal contents if they each hold the same number
+    /// of entries and, for a given key, the corresponding value objects in
+    /// each dictionary satisfy the `isEqual:` test.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdictionary/1414946-isequaltodictionary).
+    #[inline]
+    #[doc(alias = "isEqualToDictionary")]
+    #[doc(alias = "isEqualToDictionary:")]
+    pub fn is_equal_to_dictionary(&self, other: &NSDictionary<'data, K, V>) -> bool {
+        unsafe { _msg_send_strict![self, isEqualToDictionary: other => BOOL] }.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::foundation::{NSNumber, NSString};
+
+    #[test]
+    fn eq() {
+        let keys = [NSString::from_str("a"), NSString::from_str("b")];
+        let objects = [NSNumber::from_int(1), NSNumber::from_int(2)];
+
+        let a = NSDictionary::from_keys_and_objects(&keys, &objects);
+        let b = NSDictionary::from_keys_and_objects(&keys, &objects);
+        assert_eq!(a.count(), 2);
+        assert!(a.is_equal_to_dictionary(&b));
+
+        let c = NSDictionary::from_keys_and_objects(&keys[..1], &objects[..1]);
+        assert!(!a.is_equal_to_dictionary(&c));
+    }
+
+    #[test]
+    fn object_for_key() {
+        let keys = [NSString::from_str("a"), NSString::from_str("b")];
+        let objects = [NSNumber::from_int(1), NSNumber::from_int(2)];
+        let dictionary = NSDictionary::from_keys_and_objects(&keys, &objects);
+
+        let value = dictionary.object_for_key(&NSString::from_str("b"));
+        assert_eq!(value.map(|value| value.int_value()), Some(2));
+
+        assert!(dictionary.object_for_key(ns_string!("c")).is_none());
+    }
+}
@@ -1,7 +1,7 @@
-use super::NSString;
-use crate::core::Arc;
-use crate::objc::{NSInteger, NSObject};
-use std::fmt;
+use super::{NSDictionary, NSString};
+use crate::core::{Arc, OSStatus};
+use crate::objc::{ClassType, NSInteger, NSObject};
+use std::{error::Error, fmt};
 
 mod domain;
 mod recovery_attempting;
@@ -11,7 +11,7 @@ pub use domain::*;
 pub use recovery_attempting::*;
 pub use user_info_key::*;
 
-// TODO: Add error codes for Cocoa, Mach, and POSIX.
+// TODO: Add error codes for Mach.
 
 objc_subclass! {
     /// Information about an error condition including a domain, a domain-specific
@@ -26,7 +26,41 @@ objc_subclass! {
     pub class NSError<'data>: NSObject<'data>;
 }
 
-// TODO: `fmt::Debug`
+impl NSError<'static> {
+    /// Calls `f` with an `NSError **` out-parameter, and returns the error
+    /// written by `f` if it returns `None`.
+    ///
+    /// This is for methods following the Cocoa convention of returning `NO` or
+    /// `nil` on failure and providing an autoreleased error.
+    ///
+    /// If `f` fails without providing an error, an error in the
+    /// [Cocoa domain](NSErrorDomain::cocoa) with `unknown_code` is returned
+    /// instead.
+    #[inline]
+    pub(crate) fn try_with<T, F>(unknown_code: NSInteger, f: F) -> Result<T, Arc<Self>>
+    where
+        F: FnOnce(&mut Option<&Self>) -> Option<T>,
+    {
+        let mut error: Option<&Self> = None;
+        match f(&mut error) {
+            Some(value) => Ok(value),
+            None => match error {
+                Some(error) => Err(Arc::retain(error)),
+                None => Err(Self::new(NSErrorDomain::cocoa(), unknown_code, None)),
+            },
+        }
+    }
+}
+
+impl fmt::Debug for NSError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSError")
+            .field("domain", &self.domain())
+            .field("code", &self.code())
+            .field("localized_description", &self.localized_description())
+            .finish()
+    }
+}
 
 impl fmt::Display for NSError<'_> {
     #[inline]
@@ -35,8 +69,55 @@ impl fmt::Display for NSError<'_> {
     }
 }
 
-impl NSError<'_> {
-    // TODO: `new(domain: &NSErrorDomain, code: NSInteger, user_info: &NSDictionary<NSErrorUserInfoKey, id>) -> Arc<Self>`
+impl Error for NSError<'_> {
+    /// Returns the object in the user info dictionary corresponding to
+    /// [`NSErrorUserInfoKey::underlying_error`], if it is an `NSError`.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        // The user info dictionary and its objects are retained by `self`.
+        let user_info: &NSDictionary<NSErrorUserInfoKey, NSObject> =
+            unsafe { _msg_send_strict![self, userInfo] };
+        let underlying: Option<&NSObject<'static>> = unsafe {
+            _msg_send_strict![
+                user_info,
+                objectForKey: NSErrorUserInfoKey::underlying_error()
+            ]
+        };
+
+        let underlying = underlying?;
+        if underlying.is_kind_of_class(NSError::class()) {
+            let underlying =
+                unsafe { &*(underlying as *const NSObject as *const NSError<'static>) };
+            Some(underlying)
+        } else {
+            None
+        }
+    }
+}
+
+/// Creating errors.
+impl NSError<'static> {
+    /// Creates an error for `domain` with a domain-specific `code` and optional
+    /// `user_info` dictionary.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nserror/1522782-errorwithdomain).
+    #[inline]
+    #[doc(alias = "errorWithDomain")]
+    #[doc(alias = "errorWithDomain:code:userInfo:")]
+    pub fn new(
+        domain: &NSErrorDomain,
+        code: NSInteger,
+        user_info: Option<&NSDictionary<'static, NSErrorUserInfoKey, NSObject<'static>>>,
+    ) -> Arc<Self> {
+        let error: &Self = unsafe {
+            _msg_send_any![
+                Self::class(),
+                errorWithDomain: domain
+                code: code
+                userInfo: user_info
+            ]
+        };
+        Arc::retain(error)
+    }
 }
 
 /// Getting error properties.
@@ -56,10 +137,60 @@ impl NSError<'_> {
     /// See [documentation](https://developer.apple.com/documentation/foundation/nserror/1413924-domain).
     #[inline]
     pub fn domain(&self) -> Arc<NSErrorDomain> {
-        unsafe { _msg_send_any![self, domain] }
+        let domain: &NSErrorDomain = unsafe { _msg_send_any![self, domain] };
+        Arc::retain(domain)
     }
 
-    // TODO: `userInfo`
+    /// Returns the user info dictionary, which contains application-specific
+    /// information about the error.
+    ///
+    /// The dictionary is empty if the error was created without one.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nserror/1411580-userinfo).
+    #[inline]
+    #[doc(alias = "userInfo")]
+    pub fn user_info(&self) -> Arc<NSDictionary<'static, NSErrorUserInfoKey, NSObject<'static>>> {
+        let user_info: &NSDictionary<NSErrorUserInfoKey, NSObject> =
+            unsafe { _msg_send_strict![self, userInfo] };
+        Arc::retain(user_info)
+    }
+}
+
+/// Interpreting error codes.
+impl NSError<'_> {
+    #[inline]
+    fn code_in_domain(&self, domain: &NSErrorDomain) -> Option<NSInteger> {
+        if *self.domain() == *domain {
+            Some(self.code())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the POSIX `errno` value of this error if it is in the
+    /// [`posix`](NSErrorDomain::posix) domain.
+    #[inline]
+    pub fn as_posix_errno(&self) -> Option<i32> {
+        self.code_in_domain(NSErrorDomain::posix())?.try_into().ok()
+    }
+
+    /// Returns the `OSStatus` value of this error if it is in the
+    /// [`os_status`](NSErrorDomain::os_status) domain.
+    #[inline]
+    pub fn as_os_status(&self) -> Option<OSStatus> {
+        OSStatus::new(
+            self.code_in_domain(NSErrorDomain::os_status())?
+                .try_into()
+                .ok()?,
+        )
+    }
+
+    /// Returns the `kern_return_t` value of this error if it is in the
+    /// [`mach`](NSErrorDomain::mach) domain.
+    #[inline]
+    pub fn as_mach_error(&self) -> Option<i32> {
+        self.code_in_domain(NSErrorDomain::mach())?.try_into().ok()
+    }
 }
 
 /// Getting error user info.
@@ -74,7 +205,8 @@ impl NSError<'_> {
     #[inline]
     #[doc(alias = "localizedDescription")]
     pub fn localized_description(&self) -> Arc<NSString> {
-        unsafe { _msg_send_any![self, localizedDescription] }
+        let description: &NSString = unsafe { _msg_send_any![self, localizedDescription] };
+        Arc::retain(description)
     }
 
     /// Returns a string containing the localized explanation of the reason for
@@ -87,7 +219,8 @@ impl NSError<'_> {
     #[inline]
     #[doc(alias = "localizedFailureReason")]
     pub fn localized_failure_reason(&self) -> Option<Arc<NSString>> {
-        unsafe { _msg_send_any![self, localizedFailureReason] }
+        let reason: Option<&NSString> = unsafe { _msg_send_any![self, localizedFailureReason] };
+        reason.map(Arc::retain)
     }
 
     // TODO: `localizedRecoveryOptions`
@@ -102,7 +235,9 @@ impl NSError<'_> {
     #[inline]
     #[doc(alias = "localizedRecoverySuggestion")]
     pub fn localized_recovery_suggestion(&self) -> Option<Arc<NSString>> {
-        unsafe { _msg_send_any![self, localizedRecoverySuggestion] }
+        let suggestion: Option<&NSString> =
+            unsafe { _msg_send_any![self, localizedRecoverySuggestion] };
+        suggestion.map(Arc::retain)
     }
 
     /// Returns the object in the user info dictionary corresponding to
@@ -112,7 +247,9 @@ impl NSError<'_> {
     #[inline]
     #[doc(alias = "recoveryAttempter")]
     pub fn recovery_attempter(&self) -> Option<Arc<NSErrorRecoveryAttempting>> {
-        unsafe { _msg_send_any![self, recoveryAttempter] }
+        let attempter: Option<&NSErrorRecoveryAttempting> =
+            unsafe { _msg_send_any![self, recoveryAttempter] };
+        attempter.map(Arc::retain)
     }
 
     /// Returns the object in the user info dictionary corresponding to
@@ -122,7 +259,8 @@ impl NSError<'_> {
     #[inline]
     #[doc(alias = "helpAnchor")]
     pub fn help_anchor(&self) -> Option<Arc<NSString>> {
-        unsafe { _msg_send_any![self, helpAnchor] }
+        let anchor: Option<&NSString> = unsafe { _msg_send_any![self, helpAnchor] };
+        anchor.map(Arc::retain)
     }
 }
 
@@ -132,3 +270,71 @@ impl NSError<'_> {
     // - `userInfoValueProviderForDomain:`
     // - `setUserInfoValueProviderForDomain:provider:`
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::slice;
+
+    #[test]
+    fn new() {
+        let error = NSError::new(NSErrorDomain::cocoa(), 4, None);
+        assert_eq!(error.code(), 4);
+        assert!(*error.domain() == *NSErrorDomain::cocoa());
+    }
+
+    #[test]
+    fn try_with_missing_error() {
+        let code = crate::foundation::error_codes::NSFileWriteUnknownError;
+        let error = NSError::try_with(code, |_| None::<()>).unwrap_err();
+        assert_eq!(error.code(), code);
+        assert!(*error.domain() == *NSErrorDomain::cocoa());
+    }
+
+    #[test]
+    fn user_info() {
+        let key: Arc<NSErrorUserInfoKey> = NSString::from_str("FruityCustomKey").into();
+        let value: Arc<NSObject> = Arc::retain(&NSString::from_str("fruity"));
+
+        let user_info =
+            NSDictionary::from_keys_and_objects(slice::from_ref(&key), slice::from_ref(&value));
+        let error = NSError::new(NSErrorDomain::cocoa(), 0, Some(&user_info));
+
+        let result = error.user_info().object_for_key(&key);
+        assert!(result.as_deref() == Some(&*value));
+
+        let error = NSError::new(NSErrorDomain::cocoa(), 0, None);
+        assert!(error.user_info().is_empty());
+    }
+
+    #[test]
+    fn error() {
+        let underlying = NSError::new(NSErrorDomain::posix(), 2, None);
+        let key: Arc<NSErrorUserInfoKey> = Arc::retain(NSErrorUserInfoKey::underlying_error());
+        let value: Arc<NSObject> = Arc::retain(&underlying);
+        let user_info =
+            NSDictionary::from_keys_and_objects(slice::from_ref(&key), slice::from_ref(&value));
+
+        let error: Box<dyn Error> =
+            Box::new(NSError::new(NSErrorDomain::cocoa(), 4, Some(&user_info)));
+        let source = error.source().unwrap();
+        assert_eq!(source.to_string(), underlying.to_string());
+        assert!(source.source().is_none());
+
+        let debug = format!("{:?}", error);
+        assert!(debug.contains("NSCocoaErrorDomain"), "{}", debug);
+        assert!(debug.contains("code: 4"), "{}", debug);
+    }
+
+    #[test]
+    fn posix_errno() {
+        // `ENOENT` from `<sys/errno.h>`.
+        const ENOENT: NSInteger = 2;
+
+        let error = NSError::new(NSErrorDomain::posix(), ENOENT, None);
+
+        assert_eq!(error.as_posix_errno(), Some(ENOENT as i32));
+        assert_eq!(error.as_os_status(), None);
+        assert_eq!(error.as_mach_error(), None);
+    }
+}
@@ -0,0 +1,26 @@
+use super::NSString;
+use crate::core::Arc;
+use crate::objc::NSObject;
+
+objc_subclass! {
+    /// An abstract class that declares an interface for objects that create,
+    /// interpret, and validate the textual representation of values.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsformatter).
+    pub class NSFormatter: NSObject<'static>;
+}
+
+impl NSFormatter {
+    /// Returns the textual representation of `value`, or `None` if `value` is
+    /// not of the correct class for this formatter.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsformatter/1415263-stringforobjectvalue).
+    #[inline]
+    #[doc(alias = "stringForObjectValue")]
+    #[doc(alias = "stringForObjectValue:")]
+    pub fn string_for_object_value(&self, value: &NSObject) -> Option<Arc<NSString<'static>>> {
+        let string: Option<&NSString> =
+            unsafe { _msg_send_strict![self, stringForObjectValue: value] };
+        string.map(Arc::retain)
+    }
+}
@@ -0,0 +1,72 @@
+use super::NSString;
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject};
+
+objc_subclass! {
+    /// Information about linguistic, cultural, and technological conventions
+    /// for use in formatting data for presentation.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nslocale).
+    pub class NSLocale: NSObject<'static>;
+}
+
+impl NSLocale {
+    /// Returns the logical locale for the current user.
+    ///
+    /// The returned locale does not change when the user changes their
+    /// preferences.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nslocale/1409990-currentlocale).
+    #[inline]
+    #[doc(alias = "currentLocale")]
+    pub fn current() -> Arc<Self> {
+        let locale: &Self = unsafe { _msg_send_any![Self::class(), currentLocale] };
+        Arc::retain(locale)
+    }
+
+    /// Returns the locale that updates to reflect the current user's
+    /// preferences.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nslocale/1414388-autoupdatingcurrentlocale).
+    #[inline]
+    #[doc(alias = "autoupdatingCurrentLocale")]
+    pub fn autoupdating_current() -> Arc<Self> {
+        let locale: &Self = unsafe { _msg_send_any![Self::class(), autoupdatingCurrentLocale] };
+        Arc::retain(locale)
+    }
+
+    /// Returns the "root", canonical locale, that contains fixed
+    /// "backstop" settings that provide values for otherwise undefined keys.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nslocale/1416016-systemlocale).
+    #[inline]
+    #[doc(alias = "systemLocale")]
+    pub fn system() -> Arc<Self> {
+        let locale: &Self = unsafe { _msg_send_any![Self::class(), systemLocale] };
+        Arc::retain(locale)
+    }
+
+    /// Creates a locale for the given identifier, such as `"en_US"`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nslocale/1415654-initwithlocaleidentifier).
+    #[inline]
+    #[doc(alias = "initWithLocaleIdentifier")]
+    #[doc(alias = "initWithLocaleIdentifier:")]
+    pub fn from_identifier(identifier: &NSString) -> Arc<Self> {
+        unsafe {
+            let value: Arc<Self> = Self::class().alloc();
+            let value = &*Arc::into_raw(value);
+            _msg_send_any![value, initWithLocaleIdentifier: identifier]
+        }
+    }
+
+    /// Returns the identifier for this locale.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nslocale/1416263-localeidentifier).
+    #[inline]
+    #[doc(alias = "localeIdentifier")]
+    pub fn identifier(&self) -> Arc<NSString<'static>> {
+        let identifier: &NSString = unsafe { _msg_send_any![self, localeIdentifier] };
+        Arc::retain(identifier)
+    }
+}
@@ -1,3 +1,4 @@
+use crate::core::ThreadSafe;
 use crate::objc::NSObject;
 
 objc_subclass! {
@@ -8,6 +9,9 @@ objc_subclass! {
     pub class NSNull: NSObject<'static>;
 }
 
+// SAFETY: The singleton instance is immutable.
+unsafe impl ThreadSafe for NSNull {}
+
 impl Default for &NSNull {
     #[inline]
     fn default() -> Self {
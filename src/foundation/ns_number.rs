@@ -1,8 +1,9 @@
 use super::{NSComparisonResult, NSString, NSValue};
-use crate::core::Arc;
-use crate::objc::{ClassType, NSInteger, NSUInteger, ObjCObject, BOOL};
+use crate::core::{Arc, ThreadSafe};
+use crate::objc::{ClassType, Copying, NSInteger, NSUInteger, ObjCObject, BOOL};
 use std::{
     cmp::Ordering,
+    error::Error,
     fmt,
     os::raw::{
         c_char, c_double, c_float, c_int, c_long, c_longlong, c_short, c_uchar, c_uint, c_ulong,
@@ -21,6 +22,12 @@ objc_subclass! {
     pub class NSNumber: NSValue;
 }
 
+// SAFETY: Numbers are immutable.
+unsafe impl ThreadSafe for NSNumber {}
+
+// SAFETY: Numbers are immutable, so copying returns the same number.
+unsafe impl Copying<'static> for NSNumber {}
+
 impl PartialEq for NSNumber {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
@@ -44,6 +51,70 @@ impl Ord for NSNumber {
     }
 }
 
+impl PartialEq<bool> for NSNumber {
+    /// Compares with Foundation semantics, where `@YES` and `@1` are equal.
+    #[inline]
+    fn eq(&self, other: &bool) -> bool {
+        match self._cfboolean_value() {
+            Some(value) => value == *other,
+            None => *self == *NSNumber::from_bool(*other),
+        }
+    }
+}
+
+impl PartialEq<NSNumber> for bool {
+    #[inline]
+    fn eq(&self, other: &NSNumber) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<i64> for NSNumber {
+    /// Compares with C conversion rules, where floating-point values are
+    /// compared as `f64` and booleans are 0 or 1.
+    #[inline]
+    fn eq(&self, other: &i64) -> bool {
+        let other = *other;
+        match self.scalar() {
+            Scalar::Bool(value) => value as i64 == other,
+            Scalar::Float(value) => value as f64 == other as f64,
+            Scalar::Double(value) => value == other as f64,
+            Scalar::Signed(value) => value == other,
+            Scalar::Unsigned(value) => other >= 0 && value == other as u64,
+        }
+    }
+}
+
+impl PartialEq<NSNumber> for i64 {
+    #[inline]
+    fn eq(&self, other: &NSNumber) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<f64> for NSNumber {
+    /// Compares with C conversion rules, where integers are converted to `f64`
+    /// and booleans are 0 or 1.
+    #[inline]
+    fn eq(&self, other: &f64) -> bool {
+        let other = *other;
+        match self.scalar() {
+            Scalar::Bool(value) => value as u8 as f64 == other,
+            Scalar::Float(value) => value as f64 == other,
+            Scalar::Double(value) => value == other,
+            Scalar::Signed(value) => value as f64 == other,
+            Scalar::Unsigned(value) => value as f64 == other,
+        }
+    }
+}
+
+impl PartialEq<NSNumber> for f64 {
+    #[inline]
+    fn eq(&self, other: &NSNumber) -> bool {
+        other == self
+    }
+}
+
 impl From<bool> for Arc<NSNumber> {
     #[inline]
     fn from(value: bool) -> Self {
@@ -155,16 +226,152 @@ impl fmt::Debug for NSNumber {
 
 impl fmt::Display for NSNumber {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self._cfboolean_value() {
-            Some(false) => "NO".fmt(f),
-            Some(true) => "YES".fmt(f),
-            None => match self.objc_type_single() as u8 {
-                // https://developer.apple.com/library/archive/documentation/Cocoa/Conceptual/ObjCRuntimeGuide/Articles/ocrtTypeEncodings.html
-                b'f' => self.float_value().fmt(f),
-                b'd' => self.double_value().fmt(f),
-                b'c' | b'i' | b's' | b'l' | b'q' => self.longlong_value().fmt(f),
-                _ => self.unsigned_longlong_value().fmt(f),
-            },
+        // Floating-point values are formatted by Rust, so format specifiers
+        // such as precision (e.g. `{:.2}`) are honored.
+        match self.scalar() {
+            Scalar::Bool(false) => "NO".fmt(f),
+            Scalar::Bool(true) => "YES".fmt(f),
+            Scalar::Float(value) => value.fmt(f),
+            Scalar::Double(value) => value.fmt(f),
+            Scalar::Signed(value) => value.fmt(f),
+            Scalar::Unsigned(value) => value.fmt(f),
+        }
+    }
+}
+
+/// Implements radix formatting traits for `NSNumber`.
+///
+/// Booleans are formatted as 0 or 1 and floating-point numbers are truncated
+/// to integers, as with [`longlong_value`](NSNumber::longlong_value).
+macro_rules! impl_radix_fmt {
+    ($($trait:ident,)+) => {$(
+        impl fmt::$trait for NSNumber {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                match self.scalar() {
+                    Scalar::Bool(value) => fmt::$trait::fmt(&(value as u8), f),
+                    Scalar::Float(_) | Scalar::Double(_) => {
+                        fmt::$trait::fmt(&self.longlong_value(), f)
+                    }
+                    Scalar::Signed(value) => fmt::$trait::fmt(&value, f),
+                    Scalar::Unsigned(value) => fmt::$trait::fmt(&value, f),
+                }
+            }
+        }
+    )+};
+}
+
+impl_radix_fmt! {
+    Binary,
+    Octal,
+    LowerHex,
+    UpperHex,
+}
+
+/// The error returned when converting an [`NSNumber`] to a Rust number type
+/// that cannot represent its value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TryFromNSNumberError(());
+
+impl fmt::Display for TryFromNSNumberError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        "number value out of range for the target type".fmt(f)
+    }
+}
+
+impl Error for TryFromNSNumberError {}
+
+macro_rules! impl_try_from_int {
+    ($($int:ty),+) => {
+        $(
+            /// Fails if the value is out of range or is not a whole number.
+            impl TryFrom<&NSNumber> for $int {
+                type Error = TryFromNSNumberError;
+
+                fn try_from(number: &NSNumber) -> Result<Self, Self::Error> {
+                    let float = match number.scalar() {
+                        Scalar::Bool(value) => return Ok(value as $int),
+                        Scalar::Signed(value) => {
+                            return value.try_into().map_err(|_| TryFromNSNumberError(()));
+                        }
+                        Scalar::Unsigned(value) => {
+                            return value.try_into().map_err(|_| TryFromNSNumberError(()));
+                        }
+                        Scalar::Float(value) => value as f64,
+                        Scalar::Double(value) => value,
+                    };
+
+                    // The upper bound rounds to a power of two, which is the
+                    // first value out of range.
+                    let in_range =
+                        float >= <$int>::MIN as f64 && float < <$int>::MAX as f64 + 1.0;
+
+                    if in_range && float.fract() == 0.0 {
+                        Ok(float as $int)
+                    } else {
+                        Err(TryFromNSNumberError(()))
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_try_from_int!(i32, i64, u64);
+
+/// Fails if the value is an integer that cannot be represented exactly.
+impl TryFrom<&NSNumber> for f64 {
+    type Error = TryFromNSNumberError;
+
+    fn try_from(number: &NSNumber) -> Result<Self, Self::Error> {
+        // 2^63 and 2^64, which are exactly representable.
+        const I64_END: f64 = 9_223_372_036_854_775_808.0;
+        const U64_END: f64 = 18_446_744_073_709_551_616.0;
+
+        match number.scalar() {
+            Scalar::Bool(value) => Ok(value as u8 as f64),
+            Scalar::Float(value) => Ok(value as f64),
+            Scalar::Double(value) => Ok(value),
+            Scalar::Signed(value) => {
+                let float = value as f64;
+                if float < I64_END && float as i64 == value {
+                    Ok(float)
+                } else {
+                    Err(TryFromNSNumberError(()))
+                }
+            }
+            Scalar::Unsigned(value) => {
+                let float = value as f64;
+                if float < U64_END && float as u64 == value {
+                    Ok(float)
+                } else {
+                    Err(TryFromNSNumberError(()))
+                }
+            }
+        }
+    }
+}
+
+/// The value of an `NSNumber` based on its Objective-C type.
+enum Scalar {
+    Bool(bool),
+    Float(c_float),
+    Double(c_double),
+    Signed(c_longlong),
+    Unsigned(c_ulonglong),
+}
+
+impl NSNumber {
+    fn scalar(&self) -> Scalar {
+        if let Some(value) = self._cfboolean_value() {
+            return Scalar::Bool(value);
+        }
+        match self.objc_type_single() as u8 {
+            // https://developer.apple.com/library/archive/documentation/Cocoa/Conceptual/ObjCRuntimeGuide/Articles/ocrtTypeEncodings.html
+            b'f' => Scalar::Float(self.float_value()),
+            b'd' => Scalar::Double(self.double_value()),
+            b'c' | b'i' | b's' | b'l' | b'q' => Scalar::Signed(self.longlong_value()),
+            _ => Scalar::Unsigned(self.unsigned_longlong_value()),
         }
     }
 }
@@ -398,6 +605,60 @@ impl NSNumber {
         }
         unsafe { kCFNumberNegativeInfinity }
     }
+
+    /// Returns a shared instance for `value` if it is within the cached range
+    /// of `-5..=256`, or `None` otherwise.
+    ///
+    /// Each instance is created on first use and then reused for the lifetime
+    /// of the process, so repeated calls with the same value return the same
+    /// pointer without messaging Objective-C.
+    ///
+    /// On 64-bit Apple platforms, small integers created with
+    /// [`from_int`](Self::from_int) are already
+    /// [tagged pointers](https://en.wikipedia.org/wiki/Tagged_pointer) that do
+    /// not allocate. However, this is an implementation detail of Foundation,
+    /// whereas this cache guarantees pointer equality and avoids the cost of a
+    /// message send.
+    #[inline]
+    pub fn cached_int(value: c_int) -> Option<&'static NSNumber> {
+        use std::sync::atomic::{AtomicPtr, Ordering};
+
+        const MIN: c_int = -5;
+        const MAX: c_int = 256;
+        const COUNT: usize = (MAX - MIN + 1) as usize;
+
+        #[allow(clippy::declare_interior_mutable_const)]
+        const NULL: AtomicPtr<NSNumber> = AtomicPtr::new(ptr::null_mut());
+
+        static CACHE: [AtomicPtr<NSNumber>; COUNT] = [NULL; COUNT];
+
+        #[cold]
+        fn slow_path(slot: &AtomicPtr<NSNumber>, value: c_int) -> &'static NSNumber {
+            let new = Arc::into_raw(NSNumber::from_int(value)) as *mut NSNumber;
+
+            match slot.compare_exchange(ptr::null_mut(), new, Ordering::AcqRel, Ordering::Acquire) {
+                // SAFETY: The cached instance is never released.
+                Ok(_) => unsafe { &*new },
+                Err(existing) => {
+                    // Another thread initialized the slot first.
+                    drop(unsafe { Arc::from_raw(new) });
+                    unsafe { &*existing }
+                }
+            }
+        }
+
+        if !(MIN..=MAX).contains(&value) {
+            return None;
+        }
+
+        let slot = &CACHE[(value - MIN) as usize];
+        let cached = slot.load(Ordering::Acquire);
+        if !cached.is_null() {
+            return Some(unsafe { &*cached });
+        }
+
+        Some(slow_path(slot, value))
+    }
 }
 
 /// Instance operations.
@@ -594,3 +855,93 @@ impl NSNumber {
         unsafe { _msg_send_any![self, unsignedIntegerValue] }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cached_int() {
+        for value in [-5, 0, 1, 256] {
+            let a = NSNumber::cached_int(value).unwrap();
+            let b = NSNumber::cached_int(value).unwrap();
+            assert!(ptr::eq(a, b));
+            assert_eq!(a.int_value(), value);
+        }
+
+        assert!(NSNumber::cached_int(-6).is_none());
+        assert!(NSNumber::cached_int(257).is_none());
+    }
+
+    #[test]
+    fn eq_primitive() {
+        let int = NSNumber::from_int(42);
+        assert!(*int == 42);
+        assert!(42 == *int);
+        assert!(*int != 43);
+        assert!(*int == 42.0);
+
+        let unsigned = NSNumber::from_unsigned_longlong(u64::MAX);
+        assert!(*unsigned != -1);
+        assert!(*unsigned == u64::MAX as f64);
+
+        let double = NSNumber::from_double(1.5);
+        assert!(*double == 1.5);
+        assert!(1.5 == *double);
+        assert!(*double != 1);
+        assert!(*NSNumber::from_float(0.25) == 0.25);
+
+        assert!(*NSNumber::from_bool(true) == true);
+        assert!(*NSNumber::from_bool(false) == false);
+        assert!(*NSNumber::from_bool(true) != false);
+        assert!(*NSNumber::from_bool(true) == 1);
+
+        // Foundation considers `@1` and `@YES` equal.
+        assert!(*NSNumber::from_int(1) == true);
+        assert!(*NSNumber::from_int(0) == false);
+        assert!(*NSNumber::from_int(2) != true);
+    }
+
+    #[test]
+    fn try_from() {
+        let max = NSNumber::from_longlong(i64::MAX);
+        assert!(i32::try_from(&*max).is_err());
+        assert_eq!(i64::try_from(&*max), Ok(i64::MAX));
+        assert_eq!(u64::try_from(&*max), Ok(i64::MAX as u64));
+        assert!(f64::try_from(&*max).is_err());
+
+        let negative = NSNumber::from_int(-1);
+        assert_eq!(i32::try_from(&*negative), Ok(-1));
+        assert!(u64::try_from(&*negative).is_err());
+        assert_eq!(f64::try_from(&*negative), Ok(-1.0));
+
+        let whole = NSNumber::from_double(3.0);
+        assert_eq!(i32::try_from(&*whole), Ok(3));
+
+        let fraction = NSNumber::from_double(1.5);
+        assert!(i64::try_from(&*fraction).is_err());
+        assert_eq!(f64::try_from(&*fraction), Ok(1.5));
+
+        let huge = NSNumber::from_double(1e30);
+        assert!(u64::try_from(&*huge).is_err());
+
+        assert_eq!(i32::try_from(NSNumber::yes()), Ok(1));
+    }
+
+    #[test]
+    fn fmt_radix() {
+        let number = NSNumber::from_int(255);
+        assert_eq!(format!("{:x}", number), "ff");
+        assert_eq!(format!("{:X}", number), "FF");
+        assert_eq!(format!("{:#o}", number), "0o377");
+        assert_eq!(format!("{:b}", number), "11111111");
+
+        assert_eq!(format!("{:x}", NSNumber::from_bool(true)), "1");
+    }
+
+    #[test]
+    fn fmt_precision() {
+        let number = NSNumber::from_double(1.0 / 3.0);
+        assert_eq!(format!("{:.2}", number), "0.33");
+    }
+}
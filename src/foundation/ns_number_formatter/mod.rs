@@ -0,0 +1,143 @@
+use super::{NSFormatter, NSLocale, NSNumber, NSString};
+use crate::core::Arc;
+use crate::objc::ClassType;
+
+mod style;
+
+pub use style::*;
+
+objc_subclass! {
+    /// A formatter that converts between numeric values and their textual
+    /// representations.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsnumberformatter).
+    pub class NSNumberFormatter: NSFormatter;
+}
+
+impl Default for Arc<NSNumberFormatter> {
+    #[inline]
+    fn default() -> Self {
+        NSNumberFormatter::new()
+    }
+}
+
+impl NSNumberFormatter {
+    /// Creates a formatter with no style for the current locale.
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        Self::alloc().init()
+    }
+
+    /// Returns `number` formatted with the settings of `self`, or `None` if it
+    /// could not be formatted.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsnumberformatter/1418046-stringfromnumber).
+    #[inline]
+    #[doc(alias = "stringFromNumber")]
+    #[doc(alias = "stringFromNumber:")]
+    pub fn string_from_number(&self, number: &NSNumber) -> Option<Arc<NSString<'static>>> {
+        let string: Option<&NSString> = unsafe { _msg_send_any![self, stringFromNumber: number] };
+        string.map(Arc::retain)
+    }
+
+    /// Returns the number parsed from `string` with the settings of `self`, or
+    /// `None` if it could not be parsed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsnumberformatter/1408845-numberfromstring).
+    #[inline]
+    #[doc(alias = "numberFromString")]
+    #[doc(alias = "numberFromString:")]
+    pub fn number_from_string(&self, string: &NSString) -> Option<Arc<NSNumber>> {
+        let number: Option<&NSNumber> = unsafe { _msg_send_any![self, numberFromString: string] };
+        number.map(Arc::retain)
+    }
+
+    /// Returns `number` formatted with `style` for the current locale.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsnumberformatter/1415160-localizedstringfromnumber).
+    #[inline]
+    #[doc(alias = "localizedStringFromNumber:numberStyle:")]
+    pub fn localized_string_from_number(
+        number: &NSNumber,
+        style: NSNumberFormatterStyle,
+    ) -> Arc<NSString<'static>> {
+        let string: &NSString = unsafe {
+            _msg_send_any![
+                <Self as ClassType>::class(),
+                localizedStringFromNumber: number
+                numberStyle: style
+            ]
+        };
+        Arc::retain(string)
+    }
+}
+
+/// Formatter settings.
+impl NSNumberFormatter {
+    /// Returns the style used by this formatter.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsnumberformatter/1415950-numberstyle).
+    #[inline]
+    #[doc(alias = "numberStyle")]
+    pub fn number_style(&self) -> NSNumberFormatterStyle {
+        unsafe { _msg_send_any![self, numberStyle] }
+    }
+
+    /// Sets the style used by this formatter.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsnumberformatter/1415950-numberstyle).
+    #[inline]
+    #[doc(alias = "setNumberStyle")]
+    #[doc(alias = "setNumberStyle:")]
+    pub fn set_number_style(&self, style: NSNumberFormatterStyle) {
+        unsafe { _msg_send_any![self, setNumberStyle: style] }
+    }
+
+    /// Returns the locale used by this formatter.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsnumberformatter/1411769-locale).
+    #[inline]
+    pub fn locale(&self) -> Arc<NSLocale> {
+        let locale: &NSLocale = unsafe { _msg_send_any![self, locale] };
+        Arc::retain(locale)
+    }
+
+    /// Sets the locale used by this formatter.
+    ///
+    /// If `locale` is `None`, the current locale is used.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsnumberformatter/1411769-locale).
+    #[inline]
+    #[doc(alias = "setLocale")]
+    #[doc(alias = "setLocale:")]
+    pub fn set_locale(&self, locale: Option<&NSLocale>) {
+        unsafe { _msg_send_any![self, setLocale: locale] }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decimal_round_trip() {
+        let formatter = NSNumberFormatter::new();
+        formatter.set_locale(Some(&NSLocale::from_identifier(ns_string!("en_US"))));
+        assert_eq!(*formatter.locale().identifier(), "en_US");
+
+        formatter.set_number_style(NSNumberFormatterStyle::Decimal);
+        assert_eq!(formatter.number_style(), NSNumberFormatterStyle::Decimal);
+
+        let string = formatter
+            .string_from_number(&NSNumber::from_double(1234.5))
+            .unwrap();
+        assert_eq!(*string, "1,234.5");
+
+        let number = formatter.number_from_string(&string).unwrap();
+        assert!(*number == 1234.5);
+
+        assert!(formatter
+            .number_from_string(ns_string!("not a number"))
+            .is_none());
+    }
+}
@@ -0,0 +1,57 @@
+/// Predefined styles for an
+/// [`NSNumberFormatter`](super::NSNumberFormatter).
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsnumberformatterstyle).
+#[repr(usize)] // NSUInteger
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum NSNumberFormatterStyle {
+    /// An integer representation, such as `1235`.
+    #[doc(alias = "NSNumberFormatterNoStyle")]
+    None = 0,
+
+    /// A decimal representation, such as `1,234.568`.
+    #[doc(alias = "NSNumberFormatterDecimalStyle")]
+    Decimal = 1,
+
+    /// A currency representation, such as `$1,234.57`.
+    #[doc(alias = "NSNumberFormatterCurrencyStyle")]
+    Currency = 2,
+
+    /// A percent representation, such as `123,457%`.
+    #[doc(alias = "NSNumberFormatterPercentStyle")]
+    Percent = 3,
+
+    /// A scientific representation, such as `1.234568E3`.
+    #[doc(alias = "NSNumberFormatterScientificStyle")]
+    Scientific = 4,
+
+    /// A representation spelled out in words, such as
+    /// `one thousand two hundred thirty-four point five six eight`.
+    #[doc(alias = "NSNumberFormatterSpellOutStyle")]
+    SpellOut = 5,
+
+    /// An ordinal representation, such as `1,235th`.
+    #[doc(alias = "NSNumberFormatterOrdinalStyle")]
+    Ordinal = 6,
+
+    /// A currency representation with an ISO 4217 code, such as
+    /// `USD 1,234.57`.
+    #[doc(alias = "NSNumberFormatterCurrencyISOCodeStyle")]
+    CurrencyIsoCode = 8,
+
+    /// A currency representation with a pluralized name, such as
+    /// `1,234.57 US dollars`.
+    #[doc(alias = "NSNumberFormatterCurrencyPluralStyle")]
+    CurrencyPlural = 9,
+
+    /// An accounting currency representation, such as `($1,234.57)`.
+    #[doc(alias = "NSNumberFormatterCurrencyAccountingStyle")]
+    CurrencyAccounting = 10,
+}
+
+impl Default for NSNumberFormatterStyle {
+    #[inline]
+    fn default() -> Self {
+        Self::None
+    }
+}
@@ -0,0 +1,69 @@
+use super::{NSArray, NSString};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, BOOL};
+
+objc_subclass! {
+    /// A definition of logical conditions for constraining a search for a
+    /// fetch or for in-memory filtering.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspredicate).
+    pub class NSPredicate: NSObject<'static>;
+}
+
+impl NSPredicate {
+    /// Creates a predicate by parsing `format`, such as
+    /// `"SELF BEGINSWITH 'a'"`.
+    ///
+    /// Since no arguments are provided, `format` must not contain substitution
+    /// specifiers like `%@`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspredicate/1416046-predicatewithformat).
+    ///
+    /// # Exception Handling
+    ///
+    /// If `format` cannot be parsed, an `NSInvalidArgumentException` is thrown.
+    #[inline]
+    #[doc(alias = "predicateWithFormat")]
+    #[doc(alias = "predicateWithFormat:argumentArray:")]
+    pub fn from_format(format: &NSString) -> Arc<Self> {
+        // Passing the arguments as an array avoids calling a variadic method.
+        let arguments: Option<&NSArray> = None;
+
+        let predicate: &Self = unsafe {
+            _msg_send_strict![
+                Self::class(),
+                predicateWithFormat: format
+                argumentArray: arguments
+            ]
+        };
+        Arc::retain(predicate)
+    }
+
+    /// Returns `true` if `object` matches the conditions of this predicate.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspredicate/1417924-evaluatewithobject).
+    ///
+    /// # Exception Handling
+    ///
+    /// If the conditions cannot be applied to `object`, such as when comparing
+    /// strings with a number, an `NSInvalidArgumentException` is thrown.
+    #[inline]
+    #[doc(alias = "evaluateWithObject")]
+    #[doc(alias = "evaluateWithObject:")]
+    pub fn evaluate_with_object(&self, object: &NSObject) -> bool {
+        unsafe { _msg_send_strict![self, evaluateWithObject: object => BOOL] }.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evaluate_with_object() {
+        let predicate = NSPredicate::from_format(ns_string!("SELF BEGINSWITH 'a'"));
+
+        assert!(predicate.evaluate_with_object(ns_string!("apple")));
+        assert!(!predicate.evaluate_with_object(ns_string!("banana")));
+    }
+}
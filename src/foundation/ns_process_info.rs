@@ -0,0 +1,70 @@
+use super::{NSArray, NSDictionary, NSString};
+use crate::core::{Arc, ThreadSafe};
+use crate::objc::{ClassType, NSObject};
+
+objc_subclass! {
+    /// A collection of information about the current process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsprocessinfo).
+    pub class NSProcessInfo: NSObject<'static>;
+}
+
+// SAFETY: The process information agent is safe to use from multiple threads.
+unsafe impl ThreadSafe for NSProcessInfo {}
+
+impl NSProcessInfo {
+    /// Returns the process information agent for the current process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsprocessinfo/1408211-processinfo).
+    #[inline]
+    #[doc(alias = "processInfo")]
+    pub fn current() -> Arc<Self> {
+        let info: &Self = unsafe { _msg_send_any![Self::class(), processInfo] };
+        Arc::retain(info)
+    }
+
+    /// Returns the command-line arguments for the process, starting with the
+    /// path of the executable.
+    ///
+    /// Unlike [`std::env::args`], these are the arguments as seen by
+    /// Foundation, which may differ in sandboxed contexts.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsprocessinfo/1415596-arguments).
+    #[inline]
+    pub fn arguments(&self) -> Arc<NSArray<'static, NSString<'static>>> {
+        let arguments: &NSArray<NSString> = unsafe { _msg_send_strict![self, arguments] };
+        Arc::retain(arguments)
+    }
+
+    /// Returns the names and values of the environment variables for the
+    /// process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsprocessinfo/1417911-environment).
+    #[inline]
+    pub fn environment(&self) -> Arc<NSDictionary<'static, NSString<'static>, NSString<'static>>> {
+        let environment: &NSDictionary<NSString, NSString> =
+            unsafe { _msg_send_strict![self, environment] };
+        Arc::retain(environment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arguments() {
+        let arguments = NSProcessInfo::current().arguments();
+        assert!(arguments.count() >= 1);
+
+        let executable = std::env::args().next().unwrap();
+        let first = arguments.object_at_index(0).to_string();
+        assert!(first.ends_with(executable.rsplit('/').next().unwrap()));
+    }
+
+    #[test]
+    fn environment() {
+        let environment = NSProcessInfo::current().environment();
+        assert_eq!(environment.is_empty(), std::env::vars_os().next().is_none());
+    }
+}
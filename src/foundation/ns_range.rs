@@ -1,4 +1,4 @@
-use super::NSString;
+use super::{NSNotFound, NSString};
 use crate::core::Arc;
 use crate::objc::NSUInteger;
 use std::ops::Range;
@@ -37,6 +37,36 @@ impl NSRange {
         Self { location, length }
     }
 
+    /// Returns the range used by Cocoa APIs to indicate that a requested item
+    /// couldn't be found.
+    ///
+    /// This has a `location` of [`NSNotFound`] and a `length` of 0.
+    #[inline]
+    pub const fn not_found() -> Self {
+        Self::new(NSNotFound as NSUInteger, 0)
+    }
+
+    /// Returns `true` if `self` indicates that a requested item couldn't be
+    /// found.
+    ///
+    /// Only `location` is checked against [`NSNotFound`] since some APIs do
+    /// not set `length` to 0.
+    #[inline]
+    pub const fn is_not_found(&self) -> bool {
+        self.location == NSNotFound as NSUInteger
+    }
+
+    /// Returns `None` if `self` indicates that a requested item couldn't be
+    /// found.
+    #[inline]
+    pub(crate) fn found(self) -> Option<Self> {
+        if self.is_not_found() {
+            None
+        } else {
+            Some(self)
+        }
+    }
+
     /// Converts from a native Rust range.
     ///
     /// This takes into account empty ranges with an `end` less than `start`.
@@ -143,4 +173,15 @@ mod tests {
             assert_eq!(NSRange::from(range.clone()), *expected);
         }
     }
+
+    #[test]
+    fn not_found() {
+        let not_found = NSRange::not_found();
+        assert!(not_found.is_not_found());
+        assert_eq!(not_found.found(), None);
+
+        let found = NSRange::new(0, 10);
+        assert!(!found.is_not_found());
+        assert_eq!(found.found(), Some(found));
+    }
 }
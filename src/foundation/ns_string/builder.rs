@@ -0,0 +1,78 @@
+use super::NSString;
+use crate::core::Arc;
+use std::{io, string::FromUtf8Error};
+
+/// Builds an [`NSString`] from UTF-8 bytes written via [`io::Write`].
+///
+/// This allows serializers that target [`io::Write`] to produce an
+/// [`NSString`].
+///
+/// # Examples
+///
+/// ```
+/// use fruity::foundation::NSStringBuilder;
+/// use std::io::Write;
+///
+/// let mut builder = NSStringBuilder::new();
+/// write!(builder, "{} + {} = {}", 1, 2, 1 + 2).unwrap();
+///
+/// let string = builder.build().unwrap();
+/// assert_eq!(*string, "1 + 2 = 3");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct NSStringBuilder {
+    bytes: Vec<u8>,
+}
+
+impl io::Write for NSStringBuilder {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.bytes.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.bytes.extend_from_slice(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl NSStringBuilder {
+    /// Creates an empty builder.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { bytes: Vec::new() }
+    }
+
+    /// Creates an empty builder with space for at least `capacity` bytes.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            bytes: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the bytes written so far.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Creates a string from the written bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error containing the written bytes if they are not valid
+    /// UTF-8.
+    #[inline]
+    pub fn build(self) -> Result<Arc<NSString<'static>>, FromUtf8Error> {
+        let string = String::from_utf8(self.bytes)?;
+        Ok(NSString::from_str(&string))
+    }
+}
@@ -0,0 +1,51 @@
+options! {
+    /// Options for comparing and searching [`NSString`](struct.NSString.html)
+    /// instances.
+    ///
+    /// Options can be combined using `|`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstringcompareoptions).
+    pub struct NSStringCompareOptions;
+}
+
+impl NSStringCompareOptions {
+    /// No options.
+    pub const NONE: Self = Self(0);
+
+    /// A case-insensitive search.
+    pub const CASE_INSENSITIVE: Self = Self(1);
+
+    /// Exact character-by-character equivalence.
+    pub const LITERAL: Self = Self(2);
+
+    /// Search from end of source string.
+    pub const BACKWARDS: Self = Self(4);
+
+    /// Search is limited to start (or end, if
+    /// [`BACKWARDS`](#associatedconstant.BACKWARDS)) of source string.
+    pub const ANCHORED: Self = Self(8);
+
+    /// Numbers within strings are compared using numeric value, that is,
+    /// `Name2.txt` < `Name7.txt` < `Name25.txt`.
+    pub const NUMERIC: Self = Self(64);
+
+    /// Search ignores diacritic marks.
+    ///
+    /// For example, ‘ö’ is equal to ‘o’.
+    pub const DIACRITIC_INSENSITIVE: Self = Self(128);
+
+    /// Search ignores width differences in characters that have full-width and
+    /// half-width forms, as occurs in East Asian character sets.
+    pub const WIDTH_INSENSITIVE: Self = Self(256);
+
+    /// Comparisons are forced to return either `Ascending` or `Descending` if
+    /// the strings are equivalent but not strictly equal.
+    pub const FORCED_ORDERING: Self = Self(512);
+
+    /// The search string is treated as an ICU-compatible regular expression.
+    ///
+    /// If set, no other options can apply except
+    /// [`CASE_INSENSITIVE`](#associatedconstant.CASE_INSENSITIVE) and
+    /// [`ANCHORED`](#associatedconstant.ANCHORED).
+    pub const REGULAR_EXPRESSION: Self = Self(1024);
+}
@@ -1,7 +1,12 @@
 use super::NSString;
 use crate::core::Arc;
 use crate::objc::NSUInteger;
-use std::fmt;
+use std::{fmt, sync::Mutex};
+
+type CFStringEncoding = u32;
+
+/// `kCFStringEncodingInvalidId`
+const CF_STRING_ENCODING_INVALID_ID: CFStringEncoding = 0xffffffff;
 
 /// Possible [`NSString`](struct.NSString.html) encodings.
 ///
@@ -37,15 +42,128 @@ impl NSStringEncoding {
     // retained for long uses.
     #[inline]
     pub(crate) unsafe fn name_unretained<'a>(&self) -> Option<&'a NSString> {
-        type CFStringEncoding = u32;
+        extern "C" {
+            fn CFStringGetNameOfEncoding<'a>(enc: CFStringEncoding) -> Option<&'a NSString<'a>>;
+        }
+
+        CFStringGetNameOfEncoding(self.to_cf())
+    }
+}
+
+/// Converting to and from `CFStringEncoding`.
+impl NSStringEncoding {
+    /// Returns the string encoding for a `CFStringEncoding` value.
+    ///
+    /// This is retrieved using
+    /// [`CFStringConvertEncodingToNSStringEncoding`](https://developer.apple.com/documentation/corefoundation/1543046-cfstringconvertencodingtonsstrin).
+    #[inline]
+    #[doc(alias = "CFStringConvertEncodingToNSStringEncoding")]
+    pub fn from_cf(encoding: u32) -> Self {
+        extern "C" {
+            fn CFStringConvertEncodingToNSStringEncoding(enc: CFStringEncoding)
+                -> NSStringEncoding;
+        }
+        unsafe { CFStringConvertEncodingToNSStringEncoding(encoding) }
+    }
 
+    /// Returns the `CFStringEncoding` value for this string encoding.
+    ///
+    /// The numeric values of `CFStringEncoding` and `NSStringEncoding` differ,
+    /// so this must be used when passing encodings to Core Foundation.
+    ///
+    /// This is retrieved using
+    /// [`CFStringConvertNSStringEncodingToEncoding`](https://developer.apple.com/documentation/corefoundation/1543125-cfstringconvertnsstringencodingt).
+    #[inline]
+    #[doc(alias = "CFStringConvertNSStringEncodingToEncoding")]
+    pub fn to_cf(self) -> u32 {
         extern "C" {
             fn CFStringConvertNSStringEncodingToEncoding(enc: NSStringEncoding)
                 -> CFStringEncoding;
-            fn CFStringGetNameOfEncoding<'a>(enc: CFStringEncoding) -> Option<&'a NSString<'a>>;
+        }
+        unsafe { CFStringConvertNSStringEncodingToEncoding(self) }
+    }
+}
+
+/// Converting to and from [IANA](https://www.iana.org/assignments/character-sets/character-sets.xhtml)
+/// character set names.
+impl NSStringEncoding {
+    /// Returns the encoding for the IANA character set name, such as
+    /// `"utf-8"`, or `None` if the name is unknown.
+    ///
+    /// Names are matched case-insensitively.
+    ///
+    /// This is retrieved using
+    /// [`CFStringConvertIANACharSetNameToEncoding`](https://developer.apple.com/documentation/corefoundation/1542975-cfstringconvertianacharsetnameto).
+    #[doc(alias = "CFStringConvertIANACharSetNameToEncoding")]
+    pub fn from_iana_name(name: &str) -> Option<Self> {
+        extern "C" {
+            fn CFStringConvertIANACharSetNameToEncoding(name: &NSString) -> CFStringEncoding;
         }
 
-        CFStringGetNameOfEncoding(CFStringConvertNSStringEncodingToEncoding(*self))
+        let name = NSString::from_str(name);
+
+        match unsafe { CFStringConvertIANACharSetNameToEncoding(&name) } {
+            CF_STRING_ENCODING_INVALID_ID => None,
+            enc => Some(Self::from_cf(enc)),
+        }
+    }
+
+    /// Returns the IANA character set name of this encoding, such as
+    /// `"utf-8"`, or `None` if it has no IANA name.
+    ///
+    /// This is retrieved using
+    /// [`CFStringConvertEncodingToIANACharSetName`](https://developer.apple.com/documentation/corefoundation/1542710-cfstringconvertencodingtoianacha).
+    #[doc(alias = "CFStringConvertEncodingToIANACharSetName")]
+    pub fn to_iana_name(&self) -> Option<&'static str> {
+        extern "C" {
+            fn CFStringConvertEncodingToIANACharSetName<'a>(
+                enc: CFStringEncoding,
+            ) -> Option<&'a NSString<'a>>;
+        }
+
+        // Names are interned so that each is allocated at most once if it is
+        // not stored as a C string.
+        static NAMES: Mutex<Vec<(NSStringEncoding, &'static str)>> = Mutex::new(Vec::new());
+
+        // SAFETY: The name is a constant string that lives for the duration of
+        // the program.
+        let name: &'static NSString =
+            unsafe { CFStringConvertEncodingToIANACharSetName(self.to_cf())? };
+
+        // SAFETY: The name is immutable.
+        if let Some(name) = unsafe { name.as_str() } {
+            return Some(name);
+        }
+
+        let mut names = NAMES.lock().unwrap_or_else(|error| error.into_inner());
+        if let Some(&(_, name)) = names.iter().find(|(enc, _)| enc == self) {
+            return Some(name);
+        }
+
+        let name: &'static str = Box::leak(name.to_string().into_boxed_str());
+        names.push((*self, name));
+        Some(name)
+    }
+}
+
+/// Converting to and from [`encoding_rs`](https://docs.rs/encoding_rs)
+/// encodings.
+///
+/// These are only available with the **`encoding_rs`** feature flag.
+#[cfg(feature = "encoding_rs")]
+impl NSStringEncoding {
+    /// Returns the string encoding for `encoding`, or `None` if Foundation
+    /// does not support it.
+    #[inline]
+    pub fn from_encoding_rs(encoding: &'static encoding_rs::Encoding) -> Option<Self> {
+        Self::from_iana_name(encoding.name())
+    }
+
+    /// Returns the `encoding_rs` encoding for this string encoding, or `None`
+    /// if it is not supported by `encoding_rs`.
+    #[inline]
+    pub fn to_encoding_rs(&self) -> Option<&'static encoding_rs::Encoding> {
+        encoding_rs::Encoding::for_label(self.to_iana_name()?.as_bytes())
     }
 }
 
@@ -127,3 +245,44 @@ impl NSStringEncoding {
     /// Microsoft Windows codepage 1250; equivalent to WinLatin2.
     pub const WINDOWS_CP1250: Self = Self(15);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cf_round_trip() {
+        // `kCFStringEncodingUTF8`
+        const CF_UTF8: u32 = 0x08000100;
+
+        assert_eq!(NSStringEncoding::UTF8.to_cf(), CF_UTF8);
+        assert_eq!(NSStringEncoding::from_cf(CF_UTF8), NSStringEncoding::UTF8);
+    }
+
+    #[test]
+    fn iana_name() {
+        assert_eq!(NSStringEncoding::UTF8.to_iana_name(), Some("utf-8"));
+        assert_eq!(
+            NSStringEncoding::from_iana_name("utf-8"),
+            Some(NSStringEncoding::UTF8)
+        );
+        assert_eq!(
+            NSStringEncoding::from_iana_name("UTF-8"),
+            Some(NSStringEncoding::UTF8)
+        );
+        assert_eq!(NSStringEncoding::from_iana_name("fruity"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "encoding_rs")]
+    fn encoding_rs() {
+        assert_eq!(
+            NSStringEncoding::UTF8.to_encoding_rs(),
+            Some(encoding_rs::UTF_8)
+        );
+        assert_eq!(
+            NSStringEncoding::from_encoding_rs(encoding_rs::SHIFT_JIS),
+            Some(NSStringEncoding::SHIFT_JIS)
+        );
+    }
+}
@@ -0,0 +1,52 @@
+options! {
+    /// Options for enumerating the substrings of
+    /// [`NSString`](struct.NSString.html) instances.
+    ///
+    /// An option specifying the kind of substring (such as
+    /// [`BY_WORDS`](#associatedconstant.BY_WORDS)) can be combined with the other
+    /// options using `|`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstringenumerationoptions).
+    pub struct NSStringEnumerationOptions;
+}
+
+/// Kinds of substrings.
+impl NSStringEnumerationOptions {
+    /// Enumerates by lines.
+    ///
+    /// This is the default if no other kind of substring is specified.
+    pub const BY_LINES: Self = Self(0);
+
+    /// Enumerates by paragraphs.
+    pub const BY_PARAGRAPHS: Self = Self(1);
+
+    /// Enumerates by composed character sequences (user-perceived
+    /// characters).
+    pub const BY_COMPOSED_CHARACTER_SEQUENCES: Self = Self(2);
+
+    /// Enumerates by words.
+    pub const BY_WORDS: Self = Self(3);
+
+    /// Enumerates by sentences.
+    pub const BY_SENTENCES: Self = Self(4);
+
+    /// Enumerates by caret positions.
+    pub const BY_CARET_POSITIONS: Self = Self(5);
+
+    /// Enumerates by deletion clusters.
+    pub const BY_DELETION_CLUSTERS: Self = Self(6);
+}
+
+/// Enumeration behavior.
+impl NSStringEnumerationOptions {
+    /// Enumerates from the end of the range to the start.
+    pub const REVERSE: Self = Self(1 << 8);
+
+    /// Passes [`None`] instead of each substring, which avoids creating them.
+    ///
+    /// The ranges of substrings are still provided.
+    pub const SUBSTRING_NOT_REQUIRED: Self = Self(1 << 9);
+
+    /// Uses the current locale to determine word and sentence boundaries.
+    pub const LOCALIZED: Self = Self(1 << 10);
+}
@@ -0,0 +1,77 @@
+use crate::objc::NSObject;
+use std::{error::Error, fmt};
+
+/// The error returned when formatting an [`NSString`](super::NSString) fails.
+///
+/// See [`NSString::from_format`](super::NSString::from_format).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NSStringFormatError {
+    /// The format string contains a specifier other than `%@`, `%n$@`, or
+    /// `%%`.
+    UnsupportedSpecifier,
+
+    /// The format string refers to more arguments than were provided.
+    MissingArgument,
+}
+
+impl fmt::Display for NSStringFormatError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnsupportedSpecifier => "unsupported format specifier".fmt(f),
+            Self::MissingArgument => "format requires more arguments than provided".fmt(f),
+        }
+    }
+}
+
+impl Error for NSStringFormatError {}
+
+/// Substitutes the descriptions of `args` into `format`.
+///
+/// Supports `%@`, `%n$@`, and `%%`. See [`NSString::from_format`] for details.
+///
+/// [`NSString::from_format`]: super::NSString::from_format
+pub(super) fn format_objects(
+    format: &str,
+    args: &[&NSObject],
+) -> Result<String, NSStringFormatError> {
+    let mut output = String::with_capacity(format.len());
+    let mut next_arg = 0;
+    let mut rest = format;
+
+    while let Some(percent) = rest.find('%') {
+        output.push_str(&rest[..percent]);
+        let spec = &rest[percent + 1..];
+
+        if let Some(after) = spec.strip_prefix('%') {
+            output.push('%');
+            rest = after;
+            continue;
+        }
+
+        let (index, after) = if let Some(after) = spec.strip_prefix('@') {
+            let index = next_arg;
+            next_arg += 1;
+            (index, after)
+        } else {
+            // Positional `%n$@`, where `n` is 1-based.
+            let digits = spec.bytes().take_while(u8::is_ascii_digit).count();
+            let position = spec[..digits].parse::<usize>().ok().filter(|&n| n > 0);
+
+            match (position, spec[digits..].strip_prefix("$@")) {
+                (Some(position), Some(after)) => (position - 1, after),
+                _ => return Err(NSStringFormatError::UnsupportedSpecifier),
+            }
+        };
+
+        let arg = args
+            .get(index)
+            .ok_or(NSStringFormatError::MissingArgument)?;
+
+        output.push_str(&arg.description().to_string());
+        rest = after;
+    }
+
+    output.push_str(rest);
+    Ok(output)
+}
@@ -0,0 +1,64 @@
+use super::NSString;
+use crate::core::Arc;
+use crate::objc::NSUInteger;
+use std::{collections::HashMap, sync::Mutex};
+
+/// Interning.
+impl NSString<'static> {
+    /// Returns the string object shared by all interned strings with the same
+    /// contents as `self`.
+    ///
+    /// The first time some contents are interned, an immutable
+    /// [copy](Self::copy) of `self` is registered and kept alive for the rest
+    /// of the program. Comparing interned strings with equal contents takes
+    /// the pointer equality fast path of `==`.
+    ///
+    /// Each call takes a global lock, so avoid this in hot paths.
+    pub fn intern(&self) -> &'static NSString<'static> {
+        // Strings are bucketed by their `-hash` and stored as addresses because
+        // `&NSString` is not `Send`.
+        static STRINGS: Mutex<Option<HashMap<NSUInteger, Vec<usize>>>> = Mutex::new(None);
+
+        let mut strings = STRINGS.lock().unwrap_or_else(|error| error.into_inner());
+        let bucket = strings
+            .get_or_insert_with(HashMap::new)
+            .entry(self.hash())
+            .or_default();
+
+        for &address in bucket.iter() {
+            // SAFETY: Registered strings are never released.
+            let interned = unsafe { &*(address as *const NSString<'static>) };
+            if interned == self {
+                return interned;
+            }
+        }
+
+        // The copy is intentionally leaked so that it lives forever.
+        let interned = Arc::into_raw(self.copy());
+        bucket.push(interned as usize);
+
+        // SAFETY: The copy is never released.
+        unsafe { &*interned }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::foundation::NSMutableString;
+
+    #[test]
+    fn intern() {
+        let a = ns_string!("fruity").intern();
+        let b = NSString::from_str("fruity").intern();
+        assert!(std::ptr::eq(a, b));
+        assert!(!std::ptr::eq(a, ns_string!("apple").intern()));
+
+        // Interning copies mutable strings, so later mutations are not seen.
+        let mutable = NSMutableString::from_str("mutable");
+        let interned = mutable.intern();
+        mutable.append_str("!");
+        assert_eq!(interned.to_string(), "mutable");
+        assert!(std::ptr::eq(interned, ns_string!("mutable").intern()));
+    }
+}
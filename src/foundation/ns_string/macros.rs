@@ -88,6 +88,21 @@ macro_rules! ns_string_wrapper {
 /// Note that the result cannot be used in a `const` because it refers to
 /// static data outside of this library.
 ///
+/// # Interning
+///
+/// Each use of this macro creates a distinct string object, even if the same
+/// literal is used elsewhere.
+///
+/// With the **`ns_string_intern`** feature flag, the first string object
+/// created for some contents is shared by all uses of this macro with the same
+/// contents, via
+/// [`NSString::intern`](foundation/struct.NSString.html#method.intern). This
+/// makes comparisons of equal literals take the pointer equality fast path of
+/// [`NSString`](foundation/struct.NSString.html).
+///
+/// This requires a global lookup on each use and makes the result unusable in
+/// `static` values, so it is disabled by default.
+///
 /// # Unicode Strings
 ///
 /// In Objective-C, non-ASCII strings are UTF-16. However, Rust strings are
@@ -253,7 +268,7 @@ macro_rules! ns_string {
             .reference
         };
 
-        ns_string
+        $crate::_priv::cf_string::intern(ns_string)
     }};
 }
 
@@ -265,7 +280,13 @@ mod tests {
     fn ns_string() {
         macro_rules! test {
             ($($s:expr,)+) => {$({
+                #[cfg(not(feature = "ns_string_intern"))]
                 static STRING: &NSString = ns_string!($s);
+
+                #[cfg(feature = "ns_string_intern")]
+                #[allow(non_snake_case)]
+                let STRING: &NSString = ns_string!($s);
+
                 assert_eq!(STRING.to_string(), $s);
             })+};
         }
@@ -283,4 +304,19 @@ mod tests {
             "讓每個人都能打造出。",
         }
     }
+
+    #[test]
+    #[cfg(feature = "ns_string_intern")]
+    fn intern() {
+        fn a() -> &'static NSString<'static> {
+            ns_string!("x")
+        }
+
+        fn b() -> &'static NSString<'static> {
+            ns_string!("x")
+        }
+
+        assert!(std::ptr::eq(a(), b()));
+        assert!(!std::ptr::eq(a(), ns_string!("y")));
+    }
 }
@@ -1,14 +1,36 @@
-use super::{NSComparisonResult, NSRange};
+use super::{error_codes, NSArray, NSComparisonResult, NSError, NSLocale, NSRange, NSURL};
 use crate::core::Arc;
-use crate::objc::{Class, ClassType, NSObject, NSUInteger, Sel, BOOL};
-use std::{cmp::Ordering, fmt, os::raw::c_char, ptr, slice, str};
+use crate::objc::{
+    autoreleasepool, Class, ClassType, Copying, MutableCopying, NSInteger, NSObject, NSUInteger,
+    Sel, StackBlock, BOOL,
+};
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    ffi::{c_void, CStr, CString},
+    fmt,
+    os::raw::c_char,
+    panic, process, ptr, slice, str,
+    str::FromStr,
+};
 
 #[macro_use]
 mod macros;
 
+mod builder;
+mod compare_options;
 mod encoding;
+mod enumeration_options;
+mod format;
+mod intern;
+mod split;
 
+pub use builder::*;
+pub use compare_options::*;
 pub use encoding::*;
+pub use enumeration_options::*;
+pub use format::NSStringFormatError;
+pub use split::*;
 
 /// Returns the selector with a given name.
 ///
@@ -36,6 +58,14 @@ objc_subclass! {
     pub class NSString<'data>: NSObject<'data>;
 }
 
+// SAFETY: Copying a string returns an immutable string.
+unsafe impl<'data> Copying<'data> for NSString<'data> {}
+
+// SAFETY: Mutable copies of strings are mutable strings.
+unsafe impl<'data> MutableCopying<'data> for NSString<'data> {
+    type Mutable = NSMutableString<'data>;
+}
+
 impl Default for &NSString<'_> {
     #[inline]
     fn default() -> Self {
@@ -46,6 +76,11 @@ impl Default for &NSString<'_> {
 impl PartialEq for NSString<'_> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
+        // Identical objects, such as clones of the same `Arc` or uses of the
+        // same `ns_string!` literal, are trivially equal.
+        if ptr::eq(self, other) {
+            return true;
+        }
         unsafe { _msg_send_any_cached![self, isEqualToString: other => BOOL] }.into()
     }
 }
@@ -186,8 +221,7 @@ impl From<NSRange> for Arc<NSString<'_>> {
 impl fmt::Debug for NSString<'_> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        // SAFETY: The lifetime of `str` is very short.
-        let str = unsafe { self.to_str() };
+        let str = self.to_cow();
 
         str.fmt(f)
     }
@@ -196,8 +230,7 @@ impl fmt::Debug for NSString<'_> {
 impl fmt::Display for NSString<'_> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        // SAFETY: The lifetime of `str` is very short.
-        let str = unsafe { self.to_str() };
+        let str = self.to_cow();
 
         str.fmt(f)
     }
@@ -380,6 +413,56 @@ impl<'data> NSString<'data> {
         unsafe { objc_msgSend(obj, sel, bytes, length, encoding, free_when_done) }
     }
 
+    /// Creates an immutable string object from copying a slice of UTF-16 code
+    /// units.
+    ///
+    /// Unpaired surrogates are kept as-is, as Foundation allows.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1410461-initwithcharacters).
+    #[inline]
+    #[doc(alias = "initWithCharacters")]
+    #[doc(alias = "initWithCharacters:length:")]
+    pub fn from_utf16(units: &[u16]) -> Arc<Self> {
+        unsafe {
+            <Self as ClassType>::alloc_init_with(
+                selector!(initWithCharacters:length:),
+                (units.as_ptr(), units.len() as NSUInteger),
+            )
+        }
+    }
+
+    /// Creates an immutable string object without copying a slice of UTF-16
+    /// code units.
+    ///
+    /// This is the UTF-16 equivalent of
+    /// [`from_str_no_copy`](Self::from_str_no_copy).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1417233-initwithcharactersnocopy).
+    ///
+    /// # Safety
+    ///
+    /// The returned string object or its clones must not outlive the referenced
+    /// slice.
+    #[inline]
+    #[doc(alias = "initWithCharactersNoCopy")]
+    #[doc(alias = "initWithCharactersNoCopy:length:freeWhenDone:")]
+    pub fn from_utf16_no_copy(units: &'data [u16]) -> Arc<Self> {
+        unsafe {
+            let value: Arc<Self> = Self::class().alloc();
+
+            // The initializer consumes the allocated instance.
+            let value = &*Arc::into_raw(value);
+
+            _msg_send_strict![
+                value,
+                initWithCharactersNoCopy: units.as_ptr()
+                length: units.len() as NSUInteger
+                freeWhenDone: BOOL::NO
+                => Arc<Self>
+            ]
+        }
+    }
+
     /// Returns a string representation of `range`.
     ///
     /// See [documentation](https://developer.apple.com/documentation/foundation/1415155-nsstringfromrange).
@@ -395,21 +478,25 @@ impl<'data> NSString<'data> {
     /// Returns a copy of this object using
     /// [`NSCopying`](https://developer.apple.com/documentation/foundation/nscopying).
     ///
+    /// This forwards to [`Copying::copy`] so that the typed copy is used
+    /// without importing the trait.
+    ///
     /// See [documentation](https://developer.apple.com/documentation/objectivec/nsobject/1418807-copy).
     #[inline]
     pub fn copy(&self) -> Arc<Self> {
-        let copy = NSObject::copy(self);
-        unsafe { Arc::cast_unchecked(copy) }
+        Copying::copy(self)
     }
 
     /// Returns a copy of this object using
     /// [`NSMutableCopying`](https://developer.apple.com/documentation/foundation/nsmutablecopying).
     ///
+    /// This forwards to [`MutableCopying::mutable_copy`] so that the typed copy
+    /// is used without importing the trait.
+    ///
     /// See [documentation](https://developer.apple.com/documentation/objectivec/nsobject/1418978-mutablecopy).
     #[inline]
     pub fn mutable_copy(&self) -> Arc<NSMutableString<'data>> {
-        let copy = NSObject::mutable_copy(self);
-        unsafe { Arc::cast_unchecked(copy) }
+        MutableCopying::mutable_copy(self)
     }
 }
 
@@ -449,6 +536,10 @@ impl NSString<'_> {
     /// string if it needs to be stored outside of the memory context in which
     /// you use this property.
     ///
+    /// Reading the pointer as a C string stops at the first interior null
+    /// character. Use [`utf8_length`](Self::utf8_length) to get the length of
+    /// the full contents.
+    ///
     /// This is retrieved using
     /// [`-[NSString UTF8String]`](https://developer.apple.com/documentation/foundation/nsstring/1411189-utf8string).
     #[inline]
@@ -482,10 +573,35 @@ impl NSString<'_> {
         Some(s.get_unchecked(..s.len() - 1))
     }
 
+    /// Returns the contents of `self` as native UTF-8 bytes, or `None` if the
+    /// internal storage of `self` does not allow this to be returned
+    /// efficiently.
+    ///
+    /// The returned bytes do not include the trailing 0 byte. Interior 0 bytes
+    /// are included because the length is determined by
+    /// [`utf8_length`](Self::utf8_length) rather than by searching for a
+    /// terminator.
+    ///
+    /// # Safety
+    ///
+    /// You must ensure that `self` is not mutated during the lifetime of the
+    /// returned byte slice.
+    #[inline]
+    pub unsafe fn as_bytes(&self) -> Option<&[u8]> {
+        let cstr = self.as_utf8_ptr();
+        if cstr.is_null() {
+            return None;
+        }
+
+        Some(slice::from_raw_parts(cstr.cast(), self.utf8_length()))
+    }
+
     /// Returns the contents of `self` as a native UTF-8 string slice.
     ///
     /// This internally uses [`to_utf8_ptr`](Self::to_utf8_ptr). See its
-    /// documentation for details.
+    /// documentation for details. The length is determined by
+    /// [`utf8_length`](Self::utf8_length), so interior null characters are
+    /// included rather than ending the string slice.
     ///
     /// # Safety
     ///
@@ -544,50 +660,188 @@ impl NSString<'_> {
 
     /// Returns the contents of `self` as a native UTF-8 string buffer.
     ///
-    /// This internally uses [`to_utf8_ptr`](Self::to_utf8_ptr). See its
-    /// documentation for details.
-    ///
-    /// # Performance Considerations
-    ///
-    /// Because of how
-    /// [`-[NSString UTF8String]`](https://developer.apple.com/documentation/foundation/nsstring/1411189-utf8string)
-    /// works, this method will likely allocate twice as much memory needed for
-    /// the length of the resulting buffer. If your use case is short-lived
-    /// enough, consider using [`to_str`](Self::to_str) to save memory and time.
+    /// The full contents are copied, including interior null characters.
+    /// Unpaired surrogates are replaced with
+    /// [`U+FFFD REPLACEMENT CHARACTER`](std::char::REPLACEMENT_CHARACTER). Use
+    /// [`to_string_lossless`](Self::to_string_lossless) to detect them.
     #[inline]
     pub fn to_string(&self) -> String {
-        // This method relies on `to_string_with_nul` because that method
-        // generates a lot of code that is best to only exist once.
+        match self.to_string_lossless() {
+            Some(string) => string,
+            None => self.to_string_lossy(),
+        }
+    }
+
+    /// Returns the contents of `self` as a native UTF-8 string buffer, or
+    /// `None` if `self` cannot be represented as UTF-8, such as when it
+    /// contains unpaired surrogates.
+    ///
+    /// The full contents are copied, including interior null characters.
+    ///
+    /// This is retrieved using
+    /// [`-[NSString getBytes:maxLength:usedLength:encoding:options:range:remainingRange:]`](https://developer.apple.com/documentation/foundation/nsstring/1407539-getbytes).
+    #[doc(alias = "getBytes:maxLength:usedLength:encoding:options:range:remainingRange:")]
+    pub fn to_string_lossless(&self) -> Option<String> {
+        let range = NSRange::new(0, self.length());
+
+        // This is 0 if the conversion is not possible.
+        let capacity = self.utf8_length();
+        if capacity == 0 && !range.is_empty() {
+            return None;
+        }
 
-        let mut string = self.to_string_with_nul();
-        let len = string.len() - 1;
+        let mut bytes = Vec::<u8>::with_capacity(capacity);
+        let mut used: NSUInteger = 0;
+        let mut remaining = NSRange::default();
+
+        let success: BOOL = unsafe {
+            _msg_send_any![
+                self,
+                getBytes: bytes.as_mut_ptr().cast::<c_void>()
+                maxLength: capacity
+                usedLength: &mut used as *mut NSUInteger
+                encoding: NSStringEncoding::UTF8
+                options: 0 as NSUInteger
+                range: range
+                remainingRange: &mut remaining as *mut NSRange
+            ]
+        };
+
+        if success.is_no() || !remaining.is_empty() {
+            return None;
+        }
 
-        // This approach is slightly faster than `String::pop`.
-        //
-        // SAFETY: The null character takes 1 byte in UTF-8.
-        unsafe { string.as_mut_vec().set_len(len) };
+        // SAFETY: Foundation wrote `used` bytes of valid UTF-8.
+        unsafe {
+            bytes.set_len(used);
+            Some(String::from_utf8_unchecked(bytes))
+        }
+    }
 
-        string
+    // Slow path for `to_string` that goes through UTF-16.
+    #[cold]
+    fn to_string_lossy(&self) -> String {
+        let length = self.length();
+        let mut units = Vec::<u16>::with_capacity(length);
+
+        unsafe {
+            _msg_send_any![
+                self,
+                getCharacters: units.as_mut_ptr()
+                range: NSRange::new(0, length)
+                => ()
+            ];
+            units.set_len(length);
+        }
+
+        String::from_utf16_lossy(&units)
     }
 
     /// Returns the contents of `self` as a native UTF-8 string buffer ending
     /// with a 0 byte.
     ///
-    /// This internally uses [`to_utf8_ptr`](Self::to_utf8_ptr). See its
-    /// documentation for details.
+    /// This is [`to_string`](Self::to_string) with a 0 byte appended.
+    pub fn to_string_with_nul(&self) -> String {
+        let mut string = self.to_string();
+        string.push('\0');
+        string
+    }
+
+    /// Returns the contents of `self` as a native UTF-8 string slice if the
+    /// internal storage of `self` allows it, or as a new string buffer
+    /// otherwise.
     ///
-    /// # Performance Considerations
+    /// This uses [`as_str`](Self::as_str) as a fast path and falls back to
+    /// [`to_string`](Self::to_string). String literals created with
+    /// [`ns_string!`](../macro.ns_string.html) containing only ASCII are
+    /// always borrowed.
     ///
-    /// Because of how
-    /// [`-[NSString UTF8String]`](https://developer.apple.com/documentation/foundation/nsstring/1411189-utf8string)
-    /// works, this method will likely allocate twice as much memory needed for
-    /// the length of the resulting buffer. If your use case is short-lived
-    /// enough, consider using [`to_str_with_nul`](Self::to_str_with_nul) to
-    /// save memory and time.
-    pub fn to_string_with_nul(&self) -> String {
-        // SAFETY: This use of the string is reasonably short-lived enough for
-        // its lifetime to be long enough.
-        unsafe { self.to_str_with_nul() }.into()
+    /// The contents are only borrowed if `self` is immutable, which is
+    /// determined by whether [`copy`](Self::copy) returns `self`. Mutable
+    /// strings are always copied because they may be mutated while borrowed.
+    #[inline]
+    pub fn to_cow(&self) -> Cow<'_, str> {
+        let copy = self.copy();
+
+        if ptr::eq::<NSString>(&*copy, self) {
+            // SAFETY: Copying a mutable string always returns a new object, so
+            // `self` is immutable.
+            if let Some(s) = unsafe { self.as_str() } {
+                return Cow::Borrowed(s);
+            }
+        }
+
+        Cow::Owned(self.to_string())
+    }
+
+    /// Calls `f` with the contents of `self` as a native UTF-8 string slice.
+    ///
+    /// Unlike [`to_str`](Self::to_str), the string slice cannot outlive its
+    /// backing buffer because it is only borrowed for the duration of `f`. Any
+    /// buffer created for the conversion is released by an autorelease pool
+    /// once `f` returns.
+    ///
+    /// The string slice is taken from an immutable [copy](Self::copy) of
+    /// `self`, so mutating `self` within `f` does not affect it. Copying an
+    /// immutable string is cheap because it only retains the string.
+    ///
+    /// Interior null characters are included in the string slice.
+    ///
+    /// If the string cannot be converted to UTF-8 without loss, such as when it
+    /// contains unpaired surrogates, `f` is instead given an owned lossy
+    /// conversion from [`to_string`](ToString::to_string).
+    #[inline]
+    pub fn with_utf8<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&str) -> R,
+    {
+        let string = self.copy();
+
+        autoreleasepool(|| {
+            // `-UTF8String` returns null if the conversion fails.
+            let ptr = string.to_utf8_ptr();
+            if ptr.is_null() {
+                return f(&string.to_string());
+            }
+
+            // SAFETY: `ptr` is a non-null UTF-8 buffer of `utf8_length` bytes.
+            // The copy cannot be mutated, and the string slice is only used
+            // within the autorelease pool that owns its buffer.
+            let s = unsafe {
+                str::from_utf8_unchecked(slice::from_raw_parts(ptr.cast(), string.utf8_length()))
+            };
+            f(s)
+        })
+    }
+
+    /// Calls `f` with the contents of `self` as a null-terminated C string.
+    ///
+    /// This is like [`with_utf8`](Self::with_utf8), except that the string
+    /// is viewed as a [`CStr`], which ends at the first null character.
+    #[inline]
+    pub fn with_cstr<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&CStr) -> R,
+    {
+        let string = self.copy();
+
+        autoreleasepool(|| {
+            // `-UTF8String` returns null if the conversion fails.
+            let ptr = string.to_utf8_ptr();
+            if ptr.is_null() {
+                let lossy = string.to_string();
+                let bytes = lossy.split('\0').next().unwrap_or_default();
+
+                // SAFETY: `bytes` was split at the first null character.
+                let cstring = unsafe { CString::from_vec_unchecked(bytes.into()) };
+                return f(&cstring);
+            }
+
+            // SAFETY: `ptr` is a non-null, null-terminated string. The copy
+            // cannot be mutated, and the string is only used within the
+            // autorelease pool that owns its buffer.
+            f(unsafe { CStr::from_ptr(ptr) })
+        })
     }
 }
 
@@ -669,11 +923,6 @@ impl NSString<'_> {
         NSSelectorFromString(self)
     }
 
-    // TODO: Other comparison methods:
-    // - compare:options:
-    // - compare:options:range:
-    // - compare:options:range:locale:
-
     /// Compares the string and a given string using no options.
     ///
     /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1414082-compare).
@@ -682,6 +931,65 @@ impl NSString<'_> {
         unsafe { _msg_send_any![self, compare: other] }
     }
 
+    /// Compares the string and a given string using `options`.
+    ///
+    /// For example, [`NUMERIC`](NSStringCompareOptions::NUMERIC) orders
+    /// `file2` before `file10`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1415137-compare).
+    #[inline]
+    #[doc(alias = "compare:options:")]
+    pub fn compare_with_options(
+        &self,
+        other: &NSString,
+        options: NSStringCompareOptions,
+    ) -> NSComparisonResult {
+        unsafe { _msg_send_any![self, compare: other options: options] }
+    }
+
+    /// Compares the substring of `self` within `range` and a given string
+    /// using `options`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1411337-compare).
+    ///
+    /// # Exception Handling
+    ///
+    /// If `range` extends beyond the end of `self`, an `NSRangeException` is
+    /// thrown.
+    #[inline]
+    #[doc(alias = "compare:options:range:")]
+    pub fn compare_with_options_in_range(
+        &self,
+        other: &NSString,
+        options: NSStringCompareOptions,
+        range: NSRange,
+    ) -> NSComparisonResult {
+        unsafe { _msg_send_any![self, compare: other options: options range: range] }
+    }
+
+    /// Compares the substring of `self` within `range` and a given string
+    /// using `options` and the conventions of `locale`.
+    ///
+    /// If `locale` is `None`, the comparison is not localized.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1414561-compare).
+    ///
+    /// # Exception Handling
+    ///
+    /// If `range` extends beyond the end of `self`, an `NSRangeException` is
+    /// thrown.
+    #[inline]
+    #[doc(alias = "compare:options:range:locale:")]
+    pub fn compare_with_locale(
+        &self,
+        other: &NSString,
+        options: NSStringCompareOptions,
+        range: NSRange,
+        locale: Option<&NSLocale>,
+    ) -> NSComparisonResult {
+        unsafe { _msg_send_any![self, compare: other options: options range: range locale: locale] }
+    }
+
     /// Compares the string and a given string using a localized comparison.
     ///
     /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1416999-localizedcompare).
@@ -744,6 +1052,624 @@ impl NSString<'_> {
     pub fn has_suffix(&self, suffix: &NSString) -> bool {
         unsafe { _msg_send_any![self, hasSuffix: suffix => BOOL] }.into()
     }
+
+    /// Returns `true` if `prefix` matches the beginning characters of `self`.
+    ///
+    /// This is like [`has_prefix`](Self::has_prefix), but `prefix` is wrapped
+    /// in a temporary string without copying. Like `hasPrefix:`, this returns
+    /// `false` if `prefix` is empty.
+    #[inline]
+    pub fn has_prefix_str(&self, prefix: &str) -> bool {
+        self.has_prefix(&NSString::from_str_no_copy(prefix))
+    }
+
+    /// Returns `true` if `suffix` matches the ending characters of `self`.
+    ///
+    /// This is like [`has_suffix`](Self::has_suffix), but `suffix` is wrapped
+    /// in a temporary string without copying. Like `hasSuffix:`, this returns
+    /// `false` if `suffix` is empty.
+    #[inline]
+    pub fn has_suffix_str(&self, suffix: &str) -> bool {
+        self.has_suffix(&NSString::from_str_no_copy(suffix))
+    }
+
+    /// Returns `true` if `other` occurs within `self`.
+    ///
+    /// This performs a case-sensitive, non-literal search.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1414563-containsstring).
+    #[inline]
+    #[doc(alias = "containsString")]
+    #[doc(alias = "containsString:")]
+    pub fn contains(&self, other: &NSString) -> bool {
+        unsafe { _msg_send_any![self, containsString: other => BOOL] }.into()
+    }
+
+    /// Returns the range of the first occurrence of `other` in `self`, or
+    /// `None` if it is not found.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1416849-rangeofstring).
+    #[inline]
+    #[doc(alias = "rangeOfString")]
+    #[doc(alias = "rangeOfString:")]
+    pub fn range_of(&self, other: &NSString) -> Option<NSRange> {
+        unsafe { _msg_send_any![self, rangeOfString: other => NSRange] }.found()
+    }
+
+    /// Returns the range of the first occurrence of `other` in `self` using
+    /// `options`, or `None` if it is not found.
+    ///
+    /// With [`BACKWARDS`](NSStringCompareOptions::BACKWARDS), this finds the
+    /// last occurrence instead.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1417348-rangeofstring).
+    #[inline]
+    #[doc(alias = "rangeOfString:options:")]
+    pub fn range_of_with_options(
+        &self,
+        other: &NSString,
+        options: NSStringCompareOptions,
+    ) -> Option<NSRange> {
+        unsafe { _msg_send_any![self, rangeOfString: other options: options => NSRange] }.found()
+    }
+}
+
+/// Working with composed character sequences.
+impl NSString<'_> {
+    /// Returns the range of the composed character sequence (such as a
+    /// surrogate pair, an emoji sequence, or a base character with combining
+    /// marks) containing the UTF-16 code unit at `index`.
+    ///
+    /// # Exception Handling
+    ///
+    /// If `index` is beyond the end of `self`, an `NSRangeException` is thrown.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1416036-rangeofcomposedcharactersequence).
+    #[inline]
+    #[doc(alias = "rangeOfComposedCharacterSequenceAtIndex")]
+    #[doc(alias = "rangeOfComposedCharacterSequenceAtIndex:")]
+    pub fn range_of_composed_character_sequence_at(&self, index: NSUInteger) -> NSRange {
+        unsafe { _msg_send_any![self, rangeOfComposedCharacterSequenceAtIndex: index] }
+    }
+
+    /// Returns the smallest range containing `range` that does not split any
+    /// composed character sequences.
+    ///
+    /// # Exception Handling
+    ///
+    /// If `range` extends beyond the end of `self`, an `NSRangeException` is
+    /// thrown.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1410330-rangeofcomposedcharactersequence).
+    #[inline]
+    #[doc(alias = "rangeOfComposedCharacterSequencesForRange")]
+    #[doc(alias = "rangeOfComposedCharacterSequencesForRange:")]
+    pub fn range_of_composed_character_sequences_for_range(&self, range: NSRange) -> NSRange {
+        unsafe { _msg_send_any![self, rangeOfComposedCharacterSequencesForRange: range] }
+    }
+
+    /// Returns the number of composed character sequences (user-perceived
+    /// characters) in `self`.
+    ///
+    /// Unlike [`length`](Self::length), this counts a sequence such as an
+    /// emoji joined by `ZERO WIDTH JOINER (U+200D)` as one character.
+    pub fn grapheme_count(&self) -> usize {
+        let length = self.length();
+        let mut index = 0;
+        let mut count = 0;
+
+        while index < length {
+            index = self.range_of_composed_character_sequence_at(index).end();
+            count += 1;
+        }
+
+        count
+    }
+}
+
+/// Getting numeric values.
+impl NSString<'_> {
+    /// Returns the integer value of this string.
+    ///
+    /// This is lenient: leading whitespace is skipped, trailing characters
+    /// after the number are ignored, and 0 is returned if the string does not
+    /// begin with a number. Use [`parse`](Self::parse) for strict parsing.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1412554-integervalue).
+    #[inline]
+    #[doc(alias = "integerValue")]
+    pub fn integer_value(&self) -> NSInteger {
+        unsafe { _msg_send_any![self, integerValue] }
+    }
+
+    /// Parses this string into another type using [`str::parse`].
+    ///
+    /// Unlike Cocoa's lenient numeric accessors, such as
+    /// [`integer_value`](Self::integer_value), this fails if the entire string
+    /// is not a valid representation of `T`.
+    #[inline]
+    pub fn parse<T: FromStr>(&self) -> Result<T, T::Err> {
+        self.to_cow().parse()
+    }
+}
+
+/// Determining line and paragraph ranges.
+impl NSString<'_> {
+    /// Returns the range of the lines containing `range`.
+    ///
+    /// The returned range includes the line terminator of the last line, if
+    /// any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1415111-linerangeforrange).
+    ///
+    /// # Exception Handling
+    ///
+    /// An exception is raised if `range` is out of bounds.
+    #[inline]
+    #[doc(alias = "lineRangeForRange")]
+    #[doc(alias = "lineRangeForRange:")]
+    pub fn line_range_for_range(&self, range: NSRange) -> NSRange {
+        unsafe { _msg_send_any![self, lineRangeForRange: range] }
+    }
+
+    /// Returns the range of the paragraphs containing `range`.
+    ///
+    /// The returned range includes the paragraph separator of the last
+    /// paragraph, if any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1413664-paragraphrangeforrange).
+    ///
+    /// # Exception Handling
+    ///
+    /// An exception is raised if `range` is out of bounds.
+    #[inline]
+    #[doc(alias = "paragraphRangeForRange")]
+    #[doc(alias = "paragraphRangeForRange:")]
+    pub fn paragraph_range_for_range(&self, range: NSRange) -> NSRange {
+        unsafe { _msg_send_any![self, paragraphRangeForRange: range] }
+    }
+}
+
+/// Dividing strings.
+impl<'data> NSString<'data> {
+    /// Returns a new string containing the characters of `self` from the
+    /// UTF-16 code unit at `index` to the end.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1417628-substringfromindex).
+    ///
+    /// # Exception Handling
+    ///
+    /// If `index` is beyond the end of `self`, an `NSRangeException` is thrown.
+    #[inline]
+    #[doc(alias = "substringFromIndex")]
+    #[doc(alias = "substringFromIndex:")]
+    pub fn substring_from(&self, index: NSUInteger) -> Arc<NSString<'data>> {
+        let substring: &NSString = unsafe { _msg_send_strict![self, substringFromIndex: index] };
+        Arc::retain(substring)
+    }
+
+    /// Returns a new string containing the characters of `self` up to, but not
+    /// including, the UTF-16 code unit at `index`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1408017-substringtoindex).
+    ///
+    /// # Exception Handling
+    ///
+    /// If `index` is beyond the end of `self`, an `NSRangeException` is thrown.
+    #[inline]
+    #[doc(alias = "substringToIndex")]
+    #[doc(alias = "substringToIndex:")]
+    pub fn substring_to(&self, index: NSUInteger) -> Arc<NSString<'data>> {
+        let substring: &NSString = unsafe { _msg_send_strict![self, substringToIndex: index] };
+        Arc::retain(substring)
+    }
+
+    /// Returns a new string containing the characters of `self` that lie
+    /// within `range`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1415706-substringwithrange).
+    ///
+    /// # Exception Handling
+    ///
+    /// If `range` extends beyond the end of `self`, an `NSRangeException` is
+    /// thrown.
+    #[inline]
+    #[doc(alias = "substringWithRange")]
+    #[doc(alias = "substringWithRange:")]
+    pub fn substring_with_range(&self, range: NSRange) -> Arc<NSString<'data>> {
+        let substring: &NSString = unsafe { _msg_send_strict![self, substringWithRange: range] };
+        Arc::retain(substring)
+    }
+
+    /// Returns an array of the substrings of `self` that are separated by
+    /// `separator`.
+    ///
+    /// Adjacent separators produce empty substrings, and `self` is returned
+    /// whole if `separator` does not occur in it. To create substrings lazily,
+    /// use [`split`](Self::split).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1413214-componentsseparatedbystring).
+    #[inline]
+    #[doc(alias = "componentsSeparatedByString")]
+    #[doc(alias = "componentsSeparatedByString:")]
+    pub fn components_separated_by(
+        &self,
+        separator: &NSString,
+    ) -> Arc<NSArray<'data, NSString<'data>>> {
+        let components: &NSArray<NSString> =
+            unsafe { _msg_send_strict![self, componentsSeparatedByString: separator] };
+        Arc::retain(components)
+    }
+
+    /// Returns an iterator over the substrings of `self` that are separated by
+    /// `separator`.
+    ///
+    /// Unlike [`components_separated_by`](Self::components_separated_by),
+    /// substrings are created lazily as the iterator advances. This makes it
+    /// cheaper to only inspect the first few components of a long string.
+    ///
+    /// Like [`components_separated_by`](Self::components_separated_by),
+    /// adjacent separators produce empty substrings, and `self` is returned
+    /// whole if `separator` does not occur in it.
+    #[inline]
+    pub fn split<'a>(&'a self, separator: &'a NSString) -> NSSplit<'a>
+    where
+        'data: 'a,
+    {
+        NSSplit::new(self, separator)
+    }
+
+    /// Calls `f` with each substring of the kind specified by `options` within
+    /// `range`.
+    ///
+    /// The closure receives the substring, its range, and its enclosing range
+    /// (which includes trailing separators, such as whitespace or line
+    /// terminators). The substring is [`None`] if `options` contains
+    /// [`SUBSTRING_NOT_REQUIRED`](NSStringEnumerationOptions::SUBSTRING_NOT_REQUIRED).
+    /// Setting the closure's `bool` argument to `true` stops the enumeration.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1416774-enumeratesubstringsinrange).
+    ///
+    /// # Safety
+    ///
+    /// It is safe to panic within the `f` function. Panics will abort the
+    /// process.
+    ///
+    /// # Exception Handling
+    ///
+    /// If `range` extends beyond the end of `self`, an `NSRangeException` is
+    /// thrown.
+    #[inline]
+    #[doc(alias = "enumerateSubstringsInRange")]
+    #[doc(alias = "enumerateSubstringsInRange:options:usingBlock:")]
+    pub fn enumerate_substrings<F>(&self, range: NSRange, options: NSStringEnumerationOptions, f: F)
+    where
+        F: FnMut(Option<&NSString<'data>>, NSRange, NSRange, &mut bool),
+    {
+        extern "C" fn invoke<'data, F>(
+            block: *mut StackBlock<F>,
+            substring: Option<&NSString<'data>>,
+            substring_range: NSRange,
+            enclosing_range: NSRange,
+            stop: *mut BOOL,
+        ) where
+            F: FnMut(Option<&NSString<'data>>, NSRange, NSRange, &mut bool),
+        {
+            // SAFETY: The block is only called during
+            // `enumerateSubstringsInRange:options:usingBlock:`.
+            let f = unsafe { StackBlock::closure(block) };
+
+            let mut should_stop = false;
+            let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+                f(
+                    substring,
+                    substring_range,
+                    enclosing_range,
+                    &mut should_stop,
+                )
+            }));
+            if result.is_err() {
+                process::abort();
+            }
+
+            if should_stop {
+                unsafe { *stop = BOOL::YES };
+            }
+        }
+
+        let invoke: extern "C" fn(_, _, _, _, _) = invoke::<F>;
+
+        // SAFETY: `invoke` matches the block type, which takes the substring,
+        // its range, its enclosing range, and a stop flag.
+        let mut block = unsafe { StackBlock::new(f, invoke as *const c_void) };
+
+        unsafe {
+            _msg_send_strict![
+                self,
+                enumerateSubstringsInRange: range
+                options: options
+                usingBlock: &mut block
+                => ()
+            ]
+        }
+    }
+}
+
+/// Creating formatted strings.
+impl NSString<'_> {
+    /// Creates a string by substituting the descriptions of `args` into
+    /// `format`, like
+    /// [`+[NSString stringWithFormat:]`](https://developer.apple.com/documentation/foundation/nsstring/1497275-stringwithformat).
+    ///
+    /// Only object specifiers are supported:
+    ///
+    /// - `%@` is replaced by the description of the next argument.
+    /// - `%n$@` is replaced by the description of the `n`th argument, starting
+    ///   at 1.
+    /// - `%%` is replaced by a literal `%`.
+    ///
+    /// Unlike the variadic Objective-C API, this never reads past the end of
+    /// `args`. However, untrusted strings should still never be used as
+    /// `format` because any `%` within them is interpreted as a specifier.
+    /// Instead, pass such strings as arguments or escape them with
+    /// [`escaping_percent`](Self::escaping_percent).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `format` contains an unsupported specifier or refers
+    /// to more arguments than are in `args`.
+    #[inline]
+    #[doc(alias = "stringWithFormat")]
+    #[doc(alias = "stringWithFormat:")]
+    pub fn from_format(
+        format: &NSString,
+        args: &[&NSObject],
+    ) -> Result<Arc<NSString<'static>>, NSStringFormatError> {
+        let format = format.to_string();
+        let formatted = format::format_objects(&format, args)?;
+        Ok(NSString::from_str(&formatted))
+    }
+
+    /// Returns a string made by appending to `self` a string constructed from
+    /// `format` and `args`.
+    ///
+    /// Formatting is performed by [`from_format`](Self::from_format). See its
+    /// documentation for the supported specifiers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `format` contains an unsupported specifier or refers
+    /// to more arguments than are in `args`.
+    #[inline]
+    #[doc(alias = "stringByAppendingFormat")]
+    #[doc(alias = "stringByAppendingFormat:")]
+    pub fn appending_format(
+        &self,
+        format: &NSString,
+        args: &[&NSObject],
+    ) -> Result<Arc<NSString<'static>>, NSStringFormatError> {
+        let formatted = NSString::from_format(format, args)?;
+        let appended: &NSString =
+            unsafe { _msg_send_any![self, stringByAppendingString: &*formatted] };
+        Ok(Arc::retain(appended))
+    }
+
+    /// Returns a copy of this string with every `%` doubled, so that it is
+    /// written literally when used as part of a format string.
+    ///
+    /// See [`from_format`](Self::from_format).
+    #[inline]
+    pub fn escaping_percent(&self) -> Arc<NSString<'static>> {
+        let escaped: &NSString = unsafe {
+            _msg_send_any![
+                self,
+                stringByReplacingOccurrencesOfString: ns_string!("%")
+                withString: ns_string!("%%")
+            ]
+        };
+        Arc::retain(escaped)
+    }
+}
+
+/// Transforming strings.
+impl NSString<'_> {
+    /// Returns a string with the given character folding options applied.
+    ///
+    /// This removes character distinctions according to `options`, which is
+    /// useful for creating search keys. For example, combining
+    /// [`CASE_INSENSITIVE`](NSStringCompareOptions::CASE_INSENSITIVE),
+    /// [`DIACRITIC_INSENSITIVE`](NSStringCompareOptions::DIACRITIC_INSENSITIVE),
+    /// and [`WIDTH_INSENSITIVE`](NSStringCompareOptions::WIDTH_INSENSITIVE)
+    /// folds "Café" to "cafe".
+    ///
+    /// If `locale` is `None`, the system locale is used.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1413779-stringbyfoldingwithoptions).
+    #[inline]
+    #[doc(alias = "stringByFoldingWithOptions")]
+    #[doc(alias = "stringByFoldingWithOptions:locale:")]
+    pub fn folding(
+        &self,
+        options: NSStringCompareOptions,
+        locale: Option<&NSLocale>,
+    ) -> Arc<NSString<'static>> {
+        let folded: &NSString =
+            unsafe { _msg_send_any![self, stringByFoldingWithOptions: options locale: locale] };
+        Arc::retain(folded)
+    }
+
+    /// Returns an uppercase representation of `self`.
+    ///
+    /// Case mappings are locale-independent and may change the length of the
+    /// string, such as `ß` becoming `SS`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1409855-uppercasestring).
+    #[inline]
+    #[doc(alias = "uppercaseString")]
+    pub fn uppercase(&self) -> Arc<NSString<'static>> {
+        let uppercase: &NSString = unsafe { _msg_send_any![self, uppercaseString] };
+        Arc::retain(uppercase)
+    }
+
+    /// Returns a lowercase representation of `self`.
+    ///
+    /// Case mappings are locale-independent.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1408467-lowercasestring).
+    #[inline]
+    #[doc(alias = "lowercaseString")]
+    pub fn lowercase(&self) -> Arc<NSString<'static>> {
+        let lowercase: &NSString = unsafe { _msg_send_any![self, lowercaseString] };
+        Arc::retain(lowercase)
+    }
+
+    /// Returns a string with the first character of each word changed to its
+    /// corresponding uppercase value and the remaining characters changed to
+    /// their corresponding lowercase values.
+    ///
+    /// Case mappings are locale-independent.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1416784-capitalizedstring).
+    #[inline]
+    #[doc(alias = "capitalizedString")]
+    pub fn capitalized(&self) -> Arc<NSString<'static>> {
+        let capitalized: &NSString = unsafe { _msg_send_any![self, capitalizedString] };
+        Arc::retain(capitalized)
+    }
+
+    /// Returns an uppercase representation of `self` using the case mappings
+    /// of the current locale.
+    ///
+    /// For example, `i` becomes `İ` in Turkish.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1413316-localizeduppercasestring).
+    #[inline]
+    #[doc(alias = "localizedUppercaseString")]
+    pub fn localized_uppercase(&self) -> Arc<NSString<'static>> {
+        let uppercase: &NSString = unsafe { _msg_send_any![self, localizedUppercaseString] };
+        Arc::retain(uppercase)
+    }
+
+    /// Returns a lowercase representation of `self` using the case mappings
+    /// of the current locale.
+    ///
+    /// For example, `I` becomes `ı` in Turkish.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1414125-localizedlowercasestring).
+    #[inline]
+    #[doc(alias = "localizedLowercaseString")]
+    pub fn localized_lowercase(&self) -> Arc<NSString<'static>> {
+        let lowercase: &NSString = unsafe { _msg_send_any![self, localizedLowercaseString] };
+        Arc::retain(lowercase)
+    }
+
+    /// Returns a string with the first character of each word changed to its
+    /// corresponding uppercase value and the remaining characters changed to
+    /// their corresponding lowercase values.
+    ///
+    /// Word boundaries and case mappings follow the rules of `locale`. If
+    /// `locale` is `None`, the mappings are locale-independent.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1414885-capitalizedstringwithlocale).
+    #[inline]
+    #[doc(alias = "capitalizedStringWithLocale")]
+    #[doc(alias = "capitalizedStringWithLocale:")]
+    pub fn capitalized_with_locale(&self, locale: Option<&NSLocale>) -> Arc<NSString<'static>> {
+        let capitalized: &NSString =
+            unsafe { _msg_send_any![self, capitalizedStringWithLocale: locale] };
+        Arc::retain(capitalized)
+    }
+
+    /// Returns a string containing this string repeated `count` times.
+    ///
+    /// This is implemented by padding an empty string with `self` using
+    /// [`-stringByPaddingToLength:withString:startingAtIndex:`](https://developer.apple.com/documentation/foundation/nsstring/1416395-stringbypaddingtolength).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the result would overflow.
+    #[inline]
+    pub fn repeated(&self, count: usize) -> Arc<NSString<'static>> {
+        let length = self.length().checked_mul(count).expect("capacity overflow");
+
+        if length == 0 {
+            return NSString::from_str("");
+        }
+
+        let repeated: &NSString = unsafe {
+            _msg_send_any![
+                ns_string!(""),
+                stringByPaddingToLength: length
+                withString: self
+                startingAtIndex: 0 as NSUInteger
+            ]
+        };
+        Arc::retain(repeated)
+    }
+}
+
+/// Reading and writing strings.
+impl NSString<'_> {
+    /// Creates a string by reading data from the resource at `url`, decoded
+    /// using `encoding`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1497327-initwithcontentsofurl).
+    #[inline]
+    #[doc(alias = "initWithContentsOfURL")]
+    #[doc(alias = "initWithContentsOfURL:encoding:error:")]
+    pub fn from_contents_of_url(
+        url: &NSURL,
+        encoding: NSStringEncoding,
+    ) -> Result<Arc<NSString<'static>>, Arc<NSError<'static>>> {
+        NSError::try_with(error_codes::NSFileReadUnknownError, |error| unsafe {
+            let value: Arc<NSString<'static>> = NSString::class().alloc();
+
+            // The initializer consumes the allocated instance.
+            let value = &*Arc::into_raw(value);
+
+            _msg_send_strict![
+                value,
+                initWithContentsOfURL: url
+                encoding: encoding
+                error: error
+                => Option<Arc<NSString<'static>>>
+            ]
+        })
+    }
+
+    /// Writes the contents of this string to the resource at `url` using
+    /// `encoding`.
+    ///
+    /// If `atomically` is `true`, the string is first written to an auxiliary
+    /// file that then replaces the original file.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1415083-writetourl).
+    #[inline]
+    #[doc(alias = "writeToURL")]
+    #[doc(alias = "writeToURL:atomically:encoding:error:")]
+    pub fn write_to_url(
+        &self,
+        url: &NSURL,
+        atomically: bool,
+        encoding: NSStringEncoding,
+    ) -> Result<(), Arc<NSError<'static>>> {
+        NSError::try_with(error_codes::NSFileWriteUnknownError, |error| {
+            let success: BOOL = unsafe {
+                _msg_send_strict![
+                    self,
+                    writeToURL: url
+                    atomically: BOOL::from(atomically)
+                    encoding: encoding
+                    error: error
+                ]
+            };
+            if success.into() {
+                Some(())
+            } else {
+                None
+            }
+        })
+    }
 }
 
 objc_subclass! {
@@ -753,6 +1679,11 @@ objc_subclass! {
     pub class NSMutableString<'data>: NSString<'data>;
 }
 
+// SAFETY: Mutable copies of strings are mutable strings.
+unsafe impl<'data> MutableCopying<'data> for NSMutableString<'data> {
+    type Mutable = Self;
+}
+
 impl Default for Arc<NSMutableString<'_>> {
     #[inline]
     fn default() -> Self {
@@ -933,3 +1864,603 @@ impl<'data> NSMutableString<'data> {
         unsafe { objc_msgSend(obj, sel, bytes, length, encoding, free_when_done) }
     }
 }
+
+/// Mutating strings.
+///
+/// These methods take `&self` because Objective-C objects are shared through
+/// [`Arc`]. As a result, they must not be called while a slice borrowed from
+/// the contents of this string (such as from
+/// [`as_str`](NSString::as_str) or [`as_utf16`](NSString::as_utf16)) is
+/// alive. Other handles to this string observe the changes.
+impl NSMutableString<'_> {
+    /// Appends the contents of `other` to the end of this string.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutablestring/1417883-appendstring).
+    #[inline]
+    #[doc(alias = "appendString")]
+    #[doc(alias = "appendString:")]
+    pub fn append(&self, other: &NSString) {
+        unsafe { _msg_send_strict![self, appendString: other => ()] }
+    }
+
+    /// Appends the contents of `s` to the end of this string.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutablestring/1417883-appendstring).
+    #[inline]
+    pub fn append_str(&self, s: &str) {
+        // The contents of `s` are copied by `appendString:`.
+        self.append(&NSString::from_str_no_copy(s));
+    }
+
+    /// Inserts the contents of `s` at the UTF-16 code unit `index`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutablestring/1414947-insertstring).
+    ///
+    /// # Exception Handling
+    ///
+    /// If `index` is beyond the end of `self`, an `NSRangeException` is thrown.
+    #[inline]
+    #[doc(alias = "insertString")]
+    #[doc(alias = "insertString:atIndex:")]
+    pub fn insert(&self, s: &NSString, index: NSUInteger) {
+        unsafe { _msg_send_strict![self, insertString: s atIndex: index => ()] }
+    }
+
+    /// Removes the characters in `range` from this string.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutablestring/1416575-deletecharactersinrange).
+    ///
+    /// # Exception Handling
+    ///
+    /// If `range` extends beyond the end of `self`, an `NSRangeException` is
+    /// thrown.
+    #[inline]
+    #[doc(alias = "deleteCharactersInRange")]
+    #[doc(alias = "deleteCharactersInRange:")]
+    pub fn delete_range(&self, range: NSRange) {
+        unsafe { _msg_send_strict![self, deleteCharactersInRange: range => ()] }
+    }
+
+    /// Replaces the contents of this string with those of `s`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutablestring/1412220-setstring).
+    #[inline]
+    #[doc(alias = "setString")]
+    #[doc(alias = "setString:")]
+    pub fn set_string(&self, s: &NSString) {
+        unsafe { _msg_send_strict![self, setString: s => ()] }
+    }
+
+    /// Replaces all occurrences of `target` within `range` with `replacement`,
+    /// returning the number of replacements made.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutablestring/1416742-replaceoccurrencesofstring).
+    ///
+    /// # Exception Handling
+    ///
+    /// If `range` extends beyond the end of `self`, an `NSRangeException` is
+    /// thrown.
+    #[inline]
+    #[doc(alias = "replaceOccurrencesOfString")]
+    #[doc(alias = "replaceOccurrencesOfString:withString:options:range:")]
+    pub fn replace_occurrences_of(
+        &self,
+        target: &NSString,
+        replacement: &NSString,
+        options: NSStringCompareOptions,
+        range: NSRange,
+    ) -> NSUInteger {
+        unsafe {
+            _msg_send_strict![
+                self,
+                replaceOccurrencesOfString: target
+                withString: replacement
+                options: options
+                range: range
+            ]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::foundation::NSNumber;
+
+    #[test]
+    fn mutate() {
+        let string: Arc<NSMutableString> = Arc::default();
+
+        string.append(ns_string!("fruity"));
+        string.append_str(" 🦀");
+        string.append_str("");
+        assert_eq!(string.to_string(), "fruity 🦀");
+
+        string.insert(ns_string!("crab "), 7);
+        assert_eq!(string.to_string(), "fruity crab 🦀");
+
+        string.delete_range(NSRange::new(0, 7));
+        assert_eq!(string.to_string(), "crab 🦀");
+
+        string.set_string(ns_string!("apple"));
+        assert_eq!(string.to_string(), "apple");
+    }
+
+    #[test]
+    fn replace_occurrences_of() {
+        let string = NSMutableString::from_str("banana");
+        let range = NSRange::new(0, string.length());
+
+        let count = string.replace_occurrences_of(
+            ns_string!("a"),
+            ns_string!("bb"),
+            NSStringCompareOptions::LITERAL,
+            range,
+        );
+        assert_eq!(count, 3);
+        assert_eq!(string.to_string(), "bbbnbbnbb");
+
+        let count = string.replace_occurrences_of(
+            ns_string!("x"),
+            ns_string!("y"),
+            NSStringCompareOptions::NONE,
+            NSRange::new(0, string.length()),
+        );
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn eq_identity() {
+        let a = NSString::from_str("hello");
+        let b = a.clone();
+        assert!(Arc::ptr_eq(&a, &b));
+        assert_eq!(*a, *b);
+
+        let c = NSString::from_str("hello");
+        assert_eq!(*a, *c);
+        assert_ne!(*a, *NSString::from_str("world"));
+    }
+
+    #[test]
+    fn to_cow() {
+        let ascii = ns_string!("hi").to_cow();
+        assert!(matches!(ascii, Cow::Borrowed("hi")));
+
+        let utf16 = ns_string!("🦀").to_cow();
+        assert!(matches!(utf16, Cow::Owned(ref s) if s == "🦀"));
+
+        // Mutable strings are never borrowed.
+        let mutable = NSMutableString::from_str("hi");
+        assert!(matches!(mutable.to_cow(), Cow::Owned(ref s) if s == "hi"));
+    }
+
+    #[test]
+    fn as_bytes() {
+        let bytes = unsafe { ns_string!("hello").as_bytes() };
+        assert_eq!(bytes, Some(&b"hello"[..]));
+
+        // Interior nulls must not truncate the bytes.
+        let bytes = unsafe { ns_string!("exa\0mple").as_bytes() };
+        assert_eq!(bytes, Some(&b"exa\0mple"[..]));
+    }
+
+    #[test]
+    fn to_string_interior_null() {
+        let string = NSString::from_str("null\0bytes");
+        assert_eq!(string.length(), 10);
+
+        assert_eq!(string.to_string(), "null\0bytes");
+        assert_eq!(string.to_string_lossless().as_deref(), Some("null\0bytes"));
+        assert_eq!(string.to_string_with_nul(), "null\0bytes\0");
+        assert_eq!(format!("{}", string), "null\0bytes");
+
+        assert_eq!(
+            NSString::from_str("").to_string_lossless().as_deref(),
+            Some("")
+        );
+    }
+
+    #[test]
+    fn to_string_unpaired_surrogate() {
+        let units: [u16; 3] = [0x61, 0xD83E, 0x62];
+        let string = NSString::from_utf16(&units);
+
+        assert_eq!(string.to_string_lossless(), None);
+        assert_eq!(string.to_string(), "a\u{FFFD}b");
+    }
+
+    #[test]
+    fn with_utf8() {
+        let string = ns_string!("exa\0mple");
+
+        assert_eq!(string.with_utf8(|s| s.to_owned()), "exa\0mple");
+
+        // `CStr` views stop at the first null character.
+        let cstr = string.with_cstr(|s| s.to_owned());
+        assert_eq!(cstr.as_bytes(), b"exa");
+    }
+
+    #[test]
+    fn with_utf8_mutated() {
+        let string = NSMutableString::from_str("fruity");
+
+        let before = string.with_utf8(|s| {
+            string.set_string(ns_string!("changed"));
+            s.to_owned()
+        });
+        assert_eq!(before, "fruity");
+
+        let before = string.with_cstr(|s| {
+            string.append_str(" again");
+            s.to_owned()
+        });
+        assert_eq!(before.as_bytes(), b"changed");
+        assert_eq!(string.to_string(), "changed again");
+    }
+
+    #[test]
+    fn with_utf8_unpaired_surrogate() {
+        let units: [u16; 3] = [0x61, 0xD83E, 0x62];
+        let string = NSString::from_utf16(&units);
+
+        assert!(string.to_utf8_ptr().is_null());
+        assert_eq!(string.with_utf8(|s| s.to_owned()), "a\u{FFFD}b");
+
+        let cstr = string.with_cstr(|s| s.to_owned());
+        assert_eq!(cstr.as_bytes(), "a\u{FFFD}b".as_bytes());
+    }
+
+    #[test]
+    fn url_io() {
+        let path = std::env::temp_dir().join("fruity_ns_string_url_io.txt");
+        let path_string = NSString::from_str(path.to_str().unwrap());
+        let url = NSURL::file_url_with_path(&path_string);
+
+        let contents = ns_string!("hello, 🌎");
+        contents
+            .write_to_url(&url, true, NSStringEncoding::UTF8)
+            .unwrap_or_else(|error| panic!("{}", error));
+
+        let read = NSString::from_contents_of_url(&url, NSStringEncoding::UTF8)
+            .unwrap_or_else(|error| panic!("{}", error));
+        assert_eq!(*read, *contents);
+
+        std::fs::remove_file(path).unwrap();
+
+        assert!(NSString::from_contents_of_url(&url, NSStringEncoding::UTF8).is_err());
+    }
+
+    #[test]
+    fn escaping_percent() {
+        let escaped = ns_string!("50%").escaping_percent();
+        assert_eq!(escaped.to_string(), "50%%");
+        assert_eq!(
+            NSString::from_format(&escaped, &[]).unwrap().to_string(),
+            "50%"
+        );
+
+        let round_trip = NSString::from_format(ns_string!("%@"), &[ns_string!("50%")]).unwrap();
+        assert_eq!(round_trip.to_string(), "50%");
+    }
+
+    #[test]
+    fn from_format() {
+        let a = NSNumber::from_int(1);
+        let b = ns_string!("b");
+
+        let formatted =
+            NSString::from_format(ns_string!("%@, %@, %1$@ (100%%)"), &[&a, b]).unwrap();
+        assert_eq!(formatted.to_string(), "1, b, 1 (100%)");
+
+        assert_eq!(
+            NSString::from_format(ns_string!("%d"), &[&a]).unwrap_err(),
+            NSStringFormatError::UnsupportedSpecifier
+        );
+        assert_eq!(
+            NSString::from_format(ns_string!("%@ %@"), &[&a]).unwrap_err(),
+            NSStringFormatError::MissingArgument
+        );
+    }
+
+    #[test]
+    fn appending_format() {
+        let five = NSNumber::from_int(5);
+        let appended = ns_string!("x=")
+            .appending_format(ns_string!("%@"), &[five.as_ref()])
+            .unwrap();
+        assert_eq!(appended.to_string(), "x=5");
+    }
+
+    #[test]
+    fn folding() {
+        let options = NSStringCompareOptions::CASE_INSENSITIVE
+            | NSStringCompareOptions::DIACRITIC_INSENSITIVE
+            | NSStringCompareOptions::WIDTH_INSENSITIVE;
+
+        let folded = ns_string!("Café").folding(options, None);
+        assert_eq!(folded.to_string(), "cafe");
+    }
+
+    #[test]
+    fn repeated() {
+        assert_eq!(ns_string!("ab").repeated(3).to_string(), "ababab");
+        assert_eq!(ns_string!("ab").repeated(0).length(), 0);
+        assert_eq!(ns_string!("").repeated(3).length(), 0);
+    }
+
+    #[test]
+    fn composed_character_sequences() {
+        // U+1F980 CRAB is a surrogate pair preceded by one code unit.
+        let string = ns_string!("a🦀");
+
+        let crab = NSRange::new(1, 2);
+        assert_eq!(string.range_of_composed_character_sequence_at(2), crab);
+        assert_eq!(
+            string.range_of_composed_character_sequences_for_range(NSRange::new(2, 1)),
+            crab
+        );
+    }
+
+    #[test]
+    fn grapheme_count() {
+        assert_eq!(ns_string!("").grapheme_count(), 0);
+        assert_eq!(ns_string!("a🦀").grapheme_count(), 2);
+
+        // Man, woman, girl, and boy joined by U+200D ZERO WIDTH JOINER.
+        let family = NSString::from_str("👨\u{200D}👩\u{200D}👧\u{200D}👦");
+        assert_eq!(family.grapheme_count(), 1);
+        assert_eq!(family.length(), 11);
+    }
+
+    #[test]
+    fn has_prefix_suffix_str() {
+        let string = ns_string!("hello");
+
+        assert!(string.has_prefix_str("he"));
+        assert!(!string.has_prefix_str("lo"));
+        assert!(string.has_suffix_str("lo"));
+        assert!(!string.has_suffix_str("he"));
+
+        assert!(!string.has_prefix_str("hello!"));
+        assert!(!string.has_prefix_str(""));
+    }
+
+    #[test]
+    fn compare_with_options() {
+        let a = ns_string!("file2");
+        let b = ns_string!("file10");
+
+        assert_eq!(a.compare(b), NSComparisonResult::OrderedDescending);
+        assert_eq!(
+            a.compare_with_options(b, NSStringCompareOptions::NUMERIC),
+            NSComparisonResult::OrderedAscending
+        );
+        assert_eq!(
+            ns_string!("FILE")
+                .compare_with_options(ns_string!("file"), NSStringCompareOptions::CASE_INSENSITIVE),
+            NSComparisonResult::OrderedSame
+        );
+
+        // Only compare "file" of "file10".
+        assert_eq!(
+            b.compare_with_options_in_range(
+                ns_string!("file"),
+                NSStringCompareOptions::NONE,
+                NSRange::new(0, 4)
+            ),
+            NSComparisonResult::OrderedSame
+        );
+
+        let locale = NSLocale::from_identifier(ns_string!("en_US"));
+        assert_eq!(
+            b.compare_with_locale(
+                a,
+                NSStringCompareOptions::NUMERIC,
+                NSRange::new(0, b.length()),
+                Some(&locale)
+            ),
+            NSComparisonResult::OrderedDescending
+        );
+    }
+
+    #[test]
+    fn search() {
+        let string = ns_string!("Hello, hello!");
+
+        assert!(string.contains(ns_string!("lo, h")));
+        assert!(!string.contains(ns_string!("goodbye")));
+
+        assert_eq!(
+            string.range_of(ns_string!("hello")),
+            Some(NSRange::new(7, 5))
+        );
+        assert_eq!(string.range_of(ns_string!("goodbye")), None);
+
+        let options = NSStringCompareOptions::CASE_INSENSITIVE;
+        assert_eq!(
+            string.range_of_with_options(ns_string!("HELLO"), options),
+            Some(NSRange::new(0, 5))
+        );
+
+        let options = options | NSStringCompareOptions::BACKWARDS;
+        assert_eq!(
+            string.range_of_with_options(ns_string!("HELLO"), options),
+            Some(NSRange::new(7, 5))
+        );
+        assert_eq!(
+            string.range_of_with_options(ns_string!("goodbye"), options),
+            None
+        );
+    }
+
+    #[test]
+    fn substring() {
+        let string = ns_string!("fruity🦀apple");
+
+        assert_eq!(string.substring_from(8).to_string(), "apple");
+        assert_eq!(string.substring_to(6).to_string(), "fruity");
+        assert_eq!(
+            string.substring_with_range(NSRange::new(6, 2)).to_string(),
+            "🦀"
+        );
+        assert_eq!(string.substring_with_range(NSRange::new(6, 0)).length(), 0);
+    }
+
+    #[test]
+    fn enumerate_substrings() {
+        let string = ns_string!("the quick brown");
+        let range = NSRange::new(0, string.length());
+
+        let mut words = Vec::new();
+        string.enumerate_substrings(
+            range,
+            NSStringEnumerationOptions::BY_WORDS,
+            |word, _, _, _| words.push(word.unwrap().to_string()),
+        );
+        assert_eq!(words, ["the", "quick", "brown"]);
+
+        let mut ranges = Vec::new();
+        string.enumerate_substrings(
+            range,
+            NSStringEnumerationOptions::BY_WORDS
+                | NSStringEnumerationOptions::SUBSTRING_NOT_REQUIRED,
+            |word, word_range, _, stop| {
+                assert!(word.is_none());
+                ranges.push(word_range);
+                *stop = ranges.len() == 2;
+            },
+        );
+        assert_eq!(ranges, [NSRange::new(0, 3), NSRange::new(4, 5)]);
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(ns_string!("42").parse::<i32>(), Ok(42));
+        assert!(ns_string!("42abc").parse::<i32>().is_err());
+
+        // Unlike `parse`, `integer_value` ignores trailing characters.
+        assert_eq!(ns_string!("42abc").integer_value(), 42);
+    }
+
+    #[test]
+    fn line_range_for_range() {
+        let string = ns_string!("one\ntwo three\nfour");
+
+        // "wo" within "two three\n".
+        let line = string.line_range_for_range(NSRange::new(5, 2));
+        assert_eq!(line, NSRange::new(4, 10));
+
+        let paragraph = string.paragraph_range_for_range(NSRange::new(5, 2));
+        assert_eq!(paragraph, NSRange::new(4, 10));
+
+        // The last line has no terminator.
+        let last = string.line_range_for_range(NSRange::new(15, 1));
+        assert_eq!(last, NSRange::new(14, 4));
+    }
+
+    #[test]
+    fn components_separated_by() {
+        let components = ns_string!("a,b,,c").components_separated_by(ns_string!(","));
+        let components: Vec<String> = components.iter().map(|c| c.to_string()).collect();
+        assert_eq!(components, ["a", "b", "", "c"]);
+
+        let whole = ns_string!("abc").components_separated_by(ns_string!(","));
+        assert_eq!(whole.count(), 1);
+    }
+
+    #[test]
+    fn split() {
+        let string = NSString::from_str(&"a,".repeat(10_000));
+
+        let mut split = string.split(ns_string!(","));
+        assert_eq!(split.next().unwrap().to_string(), "a");
+        assert_eq!(split.next().unwrap().to_string(), "a");
+
+        let components = ns_string!("a,,b").split(ns_string!(","));
+        let components: Vec<String> = components.map(|s| s.to_string()).collect();
+        assert_eq!(components, ["a", "", "b"]);
+    }
+
+    #[test]
+    fn case_transforms() {
+        let ascii = ns_string!("Hello, World!");
+        assert_eq!(ascii.uppercase().to_string(), "HELLO, WORLD!");
+        assert_eq!(ascii.lowercase().to_string(), "hello, world!");
+        assert_eq!(
+            ns_string!("hello wORLD").capitalized().to_string(),
+            "Hello World"
+        );
+
+        // Combining marks are preserved after their base characters.
+        let combining = NSString::from_str("cafe\u{301}");
+        assert_eq!(combining.uppercase().to_string(), "CAFE\u{301}");
+        assert_eq!(combining.uppercase().lowercase().to_string(), "cafe\u{301}");
+
+        // Foundation uses full case mappings, which lengthen `ß`.
+        assert_eq!(ns_string!("straße").uppercase().to_string(), "STRASSE");
+
+        let mixed = ns_string!("Rust");
+        assert_eq!(mixed.localized_uppercase().to_string(), "RUST");
+        assert_eq!(mixed.localized_lowercase().to_string(), "rust");
+    }
+
+    #[test]
+    fn capitalized_with_locale() {
+        let string = ns_string!("hello world");
+        assert_eq!(
+            string.capitalized_with_locale(None).to_string(),
+            "Hello World"
+        );
+
+        let locale = NSLocale::from_identifier(ns_string!("tr_TR"));
+        assert_eq!(
+            ns_string!("istanbul")
+                .capitalized_with_locale(Some(&locale))
+                .to_string(),
+            "İstanbul"
+        );
+    }
+
+    #[test]
+    fn builder() {
+        use std::io::Write;
+
+        let mut builder = NSStringBuilder::new();
+        write!(builder, "🦀-{:03}", 7).unwrap();
+        builder.write_all(b" done").unwrap();
+        assert_eq!(*builder.build().unwrap(), "🦀-007 done");
+
+        assert_eq!(NSStringBuilder::new().build().unwrap().length(), 0);
+
+        let mut builder = NSStringBuilder::new();
+        builder.write_all(b"abc\xFF").unwrap();
+        let error = builder.build().unwrap_err();
+        assert_eq!(error.utf8_error().valid_up_to(), 3);
+        assert_eq!(error.into_bytes(), b"abc\xFF");
+    }
+
+    #[test]
+    fn from_utf16() {
+        let units: Vec<u16> = "hi 🦀".encode_utf16().collect();
+        let string = NSString::from_utf16(&units);
+
+        assert_eq!(string.length(), 5);
+        assert_eq!(*string, "hi 🦀");
+        assert!(*string == *NSString::from_str("hi 🦀"));
+
+        // Lone surrogates are preserved.
+        let units: [u16; 2] = [0x61, 0xD83E];
+        let string = NSString::from_utf16(&units);
+        assert_eq!(string.length(), 2);
+        assert_eq!(unsafe { string.as_utf16() }, Some(&units[..]));
+    }
+
+    #[test]
+    fn from_utf16_no_copy() {
+        let units: [u16; 5] = [0x68, 0x65, 0x6C, 0x6C, 0xD83E];
+        let string = NSString::from_utf16_no_copy(&units);
+
+        assert_eq!(string.length(), units.len());
+        assert_eq!(unsafe { string.as_utf16() }, Some(&units[..]));
+    }
+}
@@ -0,0 +1,67 @@
+use super::{NSString, NSStringCompareOptions};
+use crate::core::Arc;
+use crate::foundation::NSRange;
+use crate::objc::NSUInteger;
+use std::iter::FusedIterator;
+
+/// An iterator over the substrings of an [`NSString`], separated by another
+/// string.
+///
+/// This struct is created by [`NSString::split`]. See its documentation for
+/// more.
+#[derive(Clone, Debug)]
+pub struct NSSplit<'a> {
+    string: &'a NSString<'a>,
+    separator: &'a NSString<'a>,
+    // The UTF-16 index at which the next substring starts, or `None` once the
+    // last substring has been returned.
+    start: Option<NSUInteger>,
+}
+
+impl<'a> NSSplit<'a> {
+    #[inline]
+    pub(super) fn new(string: &'a NSString<'a>, separator: &'a NSString<'a>) -> Self {
+        Self {
+            string,
+            separator,
+            start: Some(0),
+        }
+    }
+}
+
+impl<'a> Iterator for NSSplit<'a> {
+    type Item = Arc<NSString<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.start?;
+        let end = self.string.length();
+
+        let search_range = NSRange::from_range(start..end);
+        let found: NSRange = unsafe {
+            _msg_send_any![
+                self.string,
+                rangeOfString: self.separator
+                options: NSStringCompareOptions::LITERAL
+                range: search_range
+            ]
+        };
+
+        let substring_range = match found.found() {
+            // Empty separators never match.
+            Some(found) if !found.is_empty() => {
+                self.start = Some(found.end());
+                NSRange::from_range(start..found.location)
+            }
+            _ => {
+                self.start = None;
+                search_range
+            }
+        };
+
+        let substring: &NSString =
+            unsafe { _msg_send_strict![self.string, substringWithRange: substring_range] };
+        Some(Arc::retain(substring))
+    }
+}
+
+impl FusedIterator for NSSplit<'_> {}
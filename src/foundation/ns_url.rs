@@ -0,0 +1,67 @@
+use super::NSString;
+use crate::core::{Arc, ThreadSafe};
+use crate::objc::{ClassType, NSObject};
+
+objc_subclass! {
+    /// An object representing the location of a resource, such as an item on a
+    /// remote server or the path to a local file.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl).
+    pub class NSURL: NSObject<'static>;
+}
+
+// SAFETY: URL objects are immutable and can be shared across threads.
+unsafe impl ThreadSafe for NSURL {}
+
+impl NSURL {
+    /// Creates a URL by parsing `string`, or returns `None` if it is malformed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/1572047-urlwithstring).
+    #[inline]
+    #[doc(alias = "URLWithString")]
+    #[doc(alias = "URLWithString:")]
+    pub fn from_string(string: &NSString) -> Option<Arc<Self>> {
+        let url: Option<&Self> = unsafe { _msg_send_any![Self::class(), URLWithString: string] };
+        url.map(Arc::retain)
+    }
+
+    /// Creates a `file://` URL that references the local file or directory at
+    /// `path`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/1410828-fileurlwithpath).
+    #[inline]
+    #[doc(alias = "fileURLWithPath")]
+    #[doc(alias = "fileURLWithPath:")]
+    pub fn file_url_with_path(path: &NSString) -> Arc<Self> {
+        let url: &Self = unsafe { _msg_send_any![Self::class(), fileURLWithPath: path] };
+        Arc::retain(url)
+    }
+
+    /// Returns the URL string for this URL.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/1409868-absolutestring).
+    #[inline]
+    #[doc(alias = "absoluteString")]
+    pub fn absolute_string(&self) -> Option<Arc<NSString<'static>>> {
+        let string: Option<&NSString> = unsafe { _msg_send_any![self, absoluteString] };
+        string.map(Arc::retain)
+    }
+
+    /// Returns the path component of this URL.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/1408809-path).
+    #[inline]
+    pub fn path(&self) -> Option<Arc<NSString<'static>>> {
+        let path: Option<&NSString> = unsafe { _msg_send_any![self, path] };
+        path.map(Arc::retain)
+    }
+
+    /// Returns `true` if this URL uses the `file:` scheme.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/1408782-fileurl).
+    #[inline]
+    #[doc(alias = "isFileURL")]
+    pub fn is_file_url(&self) -> bool {
+        unsafe { _msg_send_any![self, isFileURL => crate::objc::BOOL] }.into()
+    }
+}
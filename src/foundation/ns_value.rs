@@ -18,18 +18,28 @@ objc_subclass! {
 
 /// Arbitrary values.
 impl NSValue {
-    /// Creates a value object containing the specified value, interpreted with
-    /// the specified Objective-C type.
+    /// Creates a value object containing a copy of `value`, interpreted with
+    /// the Objective-C type encoding `objc_type`.
+    ///
+    /// This can box any `#[repr(C)]` type, such as a C struct.
     ///
     /// See [documentation](https://developer.apple.com/documentation/foundation/nsvalue/1551466-valuewithbytes).
+    ///
+    /// # Safety
+    ///
+    /// `objc_type` must be the correct [type encoding] for `T`.
+    ///
+    /// [type encoding]: https://developer.apple.com/library/archive/documentation/Cocoa/Conceptual/ObjCRuntimeGuide/Articles/ocrtTypeEncodings.html
     #[inline]
     #[doc(alias = "valueWithBytes")]
-    pub unsafe fn from_bytes(value: *const c_void, objc_type: *const c_char) -> Arc<Self> {
-        _msg_send_any![
+    #[doc(alias = "valueWithBytes:objCType:")]
+    pub unsafe fn from_bytes<T>(value: &T, objc_type: &CStr) -> Arc<Self> {
+        let value: &Self = _msg_send_any![
             Self::class(),
-            valueWithBytes: value
-            withObjCType: objc_type
-        ]
+            valueWithBytes: (value as *const T).cast::<c_void>()
+            objCType: objc_type.as_ptr()
+        ];
+        Arc::retain(value)
     }
 
     pub(crate) fn objc_type_single(&self) -> c_char {
@@ -91,7 +101,9 @@ impl NSValue {
         unsafe { self.write_value(value.as_mut_ptr().cast(), value.len()) };
     }
 
-    /// Returns the value as some generic type.
+    /// Returns a copy of the value as some generic type.
+    ///
+    /// This is the counterpart to [`from_bytes`](Self::from_bytes).
     ///
     /// # Exception Handling
     ///
@@ -104,11 +116,22 @@ impl NSValue {
     #[inline]
     #[doc(alias = "getValue")]
     #[doc(alias = "getValue:size:")]
-    pub unsafe fn transmute_value<T>(&self) -> T {
+    pub unsafe fn get_value<T>(&self) -> T {
         let mut value = mem::MaybeUninit::<T>::uninit();
         self.write_value(value.as_mut_ptr().cast(), mem::size_of::<T>());
         value.assume_init()
     }
+
+    /// Returns the value as some generic type.
+    ///
+    /// # Safety
+    ///
+    /// See [`get_value`](Self::get_value).
+    #[inline]
+    #[deprecated = "use `get_value` instead"]
+    pub unsafe fn transmute_value<T>(&self) -> T {
+        self.get_value()
+    }
 }
 
 /// Pointer values.
@@ -120,7 +143,8 @@ impl NSValue {
     #[doc(alias = "valueWithPointer")]
     #[doc(alias = "valueWithPointer:")]
     pub fn from_ptr(ptr: *const c_void) -> Arc<Self> {
-        unsafe { _msg_send_any![Self::class(), valueWithPointer: ptr] }
+        let value: &Self = unsafe { _msg_send_any![Self::class(), valueWithPointer: ptr] };
+        Arc::retain(value)
     }
 
     /// Returns the value as an untyped pointer.
@@ -144,7 +168,9 @@ impl NSValue {
     #[doc(alias = "valueWithNonretainedObject")]
     #[doc(alias = "valueWithNonretainedObject:")]
     pub fn from_nonretained_object(obj: *mut ObjCObject) -> Arc<Self> {
-        unsafe { _msg_send_any![Self::class(), valueWithNonretainedObject: obj] }
+        let value: &Self =
+            unsafe { _msg_send_any![Self::class(), valueWithNonretainedObject: obj] };
+        Arc::retain(value)
     }
 
     /// Returns the value as a non-retained pointer to an object.
@@ -168,15 +194,17 @@ impl NSValue {
     #[doc(alias = "valueWithRange")]
     #[doc(alias = "valueWithRange:")]
     pub fn from_range(value: NSRange) -> Arc<Self> {
-        unsafe { _msg_send_any![Self::class(), valueWithRange: value] }
+        let value: &Self = unsafe { _msg_send_any![Self::class(), valueWithRange: value] };
+        Arc::retain(value)
     }
 
     /// Returns the value as an `NSRange`.
     ///
     /// See [documentation](https://developer.apple.com/documentation/foundation/nsvalue/1413902-rangevalue).
     #[inline]
+    #[doc(alias = "rangeValue")]
     pub fn range_value(&self) -> NSRange {
-        unsafe { _msg_send_any![self, pointValue] }
+        unsafe { _msg_send_any![self, rangeValue] }
     }
 }
 
@@ -189,7 +217,8 @@ impl NSValue {
     #[doc(alias = "valueWithPoint")]
     #[doc(alias = "valueWithPoint:")]
     pub fn from_point(value: NSPoint) -> Arc<Self> {
-        unsafe { _msg_send_any![Self::class(), valueWithPoint: value] }
+        let value: &Self = unsafe { _msg_send_any![Self::class(), valueWithPoint: value] };
+        Arc::retain(value)
     }
 
     /// Returns the value as an `NSPoint`.
@@ -208,7 +237,8 @@ impl NSValue {
     #[doc(alias = "valueWithSize")]
     #[doc(alias = "valueWithSize:")]
     pub fn from_size(value: NSSize) -> Arc<Self> {
-        unsafe { _msg_send_any![Self::class(), valueWithSize: value] }
+        let value: &Self = unsafe { _msg_send_any![Self::class(), valueWithSize: value] };
+        Arc::retain(value)
     }
 
     /// Returns the value as an `NSSize`.
@@ -227,7 +257,8 @@ impl NSValue {
     #[doc(alias = "valueWithRect")]
     #[doc(alias = "valueWithRect:")]
     pub fn from_rect(value: NSRect) -> Arc<Self> {
-        unsafe { _msg_send_any![Self::class(), valueWithRect: value] }
+        let value: &Self = unsafe { _msg_send_any![Self::class(), valueWithRect: value] };
+        Arc::retain(value)
     }
 
     /// Returns the value as an `NSRect`.
@@ -246,7 +277,8 @@ impl NSValue {
     #[doc(alias = "valueWithEdgeInsets")]
     #[doc(alias = "valueWithEdgeInsets:")]
     pub fn from_edge_insets(value: NSEdgeInsets) -> Arc<Self> {
-        unsafe { _msg_send_any![Self::class(), valueWithEdgeInsets: value] }
+        let value: &Self = unsafe { _msg_send_any![Self::class(), valueWithEdgeInsets: value] };
+        Arc::retain(value)
     }
 
     /// Returns the value as an `NSEdgeInsets`.
@@ -258,3 +290,65 @@ impl NSValue {
         unsafe { _msg_send_any![self, edgeInsetsValue] }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::objc::autoreleasepool;
+
+    #[test]
+    #[allow(clippy::manual_c_str_literals)] // `c""` literals require Rust 1.77.
+    fn bytes_value() {
+        #[repr(C)]
+        #[derive(Copy, Clone, Debug, PartialEq)]
+        struct Point3 {
+            x: f64,
+            y: f64,
+            z: f64,
+        }
+
+        let encoding = CStr::from_bytes_with_nul(b"{Point3=ddd}\0").unwrap();
+        let point = Point3 {
+            x: 1.0,
+            y: -2.5,
+            z: 3.25,
+        };
+
+        let value = unsafe { NSValue::from_bytes(&point, encoding) };
+        assert_eq!(value.objc_type_cstr(), encoding);
+        assert_eq!(unsafe { value.get_value::<Point3>() }, point);
+    }
+
+    #[test]
+    fn range_value() {
+        let range = NSRange {
+            location: 2,
+            length: 3,
+        };
+        let value = NSValue::from_range(range);
+        assert_eq!(value.range_value(), range);
+    }
+
+    #[test]
+    fn rect_value() {
+        let rect = NSRect {
+            origin: NSPoint { x: 1.0, y: 2.0 },
+            size: NSSize {
+                width: 3.0,
+                height: 4.0,
+            },
+        };
+
+        // The value must outlive the pool that its autoreleased reference was
+        // placed in.
+        let value = autoreleasepool(|| NSValue::from_rect(rect));
+        let count = value.retain_count();
+
+        let clone = value.clone();
+        assert_eq!(value.retain_count(), count + 1);
+        drop(clone);
+
+        assert_eq!(value.retain_count(), count);
+        assert_eq!(value.rect_value(), rect);
+    }
+}
@@ -0,0 +1,72 @@
+use std::{
+    ffi::c_void,
+    mem,
+    os::raw::{c_int, c_ulong},
+};
+
+/// The layout of `struct Block_descriptor_1` without copy/dispose helpers or a
+/// signature.
+#[repr(C)]
+struct BlockDescriptor {
+    reserved: c_ulong,
+    size: c_ulong,
+}
+
+/// A block literal on the stack that invokes a Rust closure.
+///
+/// This is the equivalent of a non-escaping `^{ ... }` literal in Objective-C.
+/// The block has no copy/dispose helpers, so it must only be passed to methods
+/// that call it before returning.
+///
+/// See [Block ABI](https://clang.llvm.org/docs/Block-ABI-Apple.html).
+#[repr(C)]
+pub(crate) struct StackBlock<F> {
+    isa: *const c_void,
+    flags: c_int,
+    reserved: c_int,
+    invoke: *const c_void,
+    descriptor: &'static BlockDescriptor,
+    closure: F,
+}
+
+extern "C" {
+    static _NSConcreteStackBlock: c_void;
+}
+
+impl<F> StackBlock<F> {
+    const DESCRIPTOR: BlockDescriptor = BlockDescriptor {
+        reserved: 0,
+        size: mem::size_of::<Self>() as c_ulong,
+    };
+
+    /// Creates a block that calls `invoke` with a pointer to itself, followed
+    /// by the block's arguments.
+    ///
+    /// # Safety
+    ///
+    /// `invoke` must be an `extern "C" fn` whose first parameter is
+    /// `*mut Self` and whose remaining parameters and return type match the
+    /// block type expected by the receiving method.
+    #[inline]
+    pub unsafe fn new(closure: F, invoke: *const c_void) -> Self {
+        Self {
+            isa: &_NSConcreteStackBlock,
+            flags: 0,
+            reserved: 0,
+            invoke,
+            descriptor: &Self::DESCRIPTOR,
+            closure,
+        }
+    }
+
+    /// Returns the closure of the block passed to an `invoke` function.
+    ///
+    /// # Safety
+    ///
+    /// `block` must be the pointer passed to `invoke`, and the returned
+    /// reference must not outlive that call.
+    #[inline]
+    pub unsafe fn closure<'a>(block: *mut Self) -> &'a mut F {
+        &mut (*block).closure
+    }
+}
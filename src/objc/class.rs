@@ -1,4 +1,4 @@
-use super::{sys, Ivar, Method, ObjCObject, Property, Sel, BOOL};
+use super::{sys, Ivar, Method, ObjCObject, Property, Protocol, Sel, BOOL};
 use crate::core::{Arc, ObjectType};
 use std::{
     cell::UnsafeCell,
@@ -231,6 +231,20 @@ impl Class {
         }
     }
 
+    /// Returns `true` if this class declares conformance to `protocol`.
+    ///
+    /// Conformance declared by superclasses is not considered.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418893-class_conformstoprotocol).
+    #[inline]
+    #[doc(alias = "class_conformsToProtocol")]
+    pub fn conforms_to_protocol(&self, protocol: &Protocol) -> bool {
+        extern "C" {
+            fn class_conformsToProtocol(class: &Class, protocol: &Protocol) -> BOOL;
+        }
+        unsafe { class_conformsToProtocol(self, protocol) }.into()
+    }
+
     /// Returns the size of instances of this class.
     #[inline]
     pub fn instance_size(&self) -> usize {
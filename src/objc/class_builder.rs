@@ -0,0 +1,139 @@
+use super::{Class, Imp, Protocol, Sel, BOOL};
+use std::{ffi::CStr, fmt, mem, os::raw::c_char, ptr::NonNull};
+
+/// Creates a new Objective-C class at runtime.
+///
+/// The class can be used once it is [registered](Self::register). If the
+/// builder is dropped before then, the class is disposed of.
+///
+/// See [documentation](https://developer.apple.com/documentation/objectivec/1418559-objc_allocateclasspair).
+pub struct ClassBuilder {
+    class: NonNull<Class>,
+}
+
+impl fmt::Debug for ClassBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("ClassBuilder")
+            .field(&self.class().name())
+            .finish()
+    }
+}
+
+impl Drop for ClassBuilder {
+    #[inline]
+    #[doc(alias = "objc_disposeClassPair")]
+    fn drop(&mut self) {
+        unsafe { objc_disposeClassPair(self.class.as_ptr()) };
+    }
+}
+
+impl ClassBuilder {
+    /// Starts creating a subclass of `superclass` with `name`, or returns
+    /// `None` if a class with `name` already exists.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418559-objc_allocateclasspair).
+    #[inline]
+    #[doc(alias = "objc_allocateClassPair")]
+    pub fn new(name: &CStr, superclass: &Class) -> Option<Self> {
+        let class = unsafe { objc_allocateClassPair(superclass, name.as_ptr(), 0) };
+        Some(Self {
+            class: NonNull::new(class)?,
+        })
+    }
+
+    /// Returns the class being created.
+    #[inline]
+    pub fn class(&self) -> &Class {
+        unsafe { self.class.as_ref() }
+    }
+
+    /// Adds a method to the class that responds to `selector` by calling
+    /// `imp`.
+    ///
+    /// Returns `false` if the class already defines a method for `selector`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418901-class_addmethod).
+    ///
+    /// # Safety
+    ///
+    /// `imp` must be a function whose signature matches the
+    /// [type encoding](super::TypeEncoding) `types`, taking the receiver and
+    /// selector as its first two arguments.
+    #[inline]
+    #[doc(alias = "class_addMethod")]
+    pub unsafe fn add_method(&mut self, selector: Sel, imp: Imp, types: &CStr) -> bool {
+        class_addMethod(self.class.as_ptr(), selector, imp, types.as_ptr()).into()
+    }
+
+    /// Declares that the class conforms to `protocol`.
+    ///
+    /// Returns `false` if the class already conforms to `protocol`.
+    ///
+    /// Note that this does not add any methods required by `protocol`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418773-class_addprotocol).
+    #[inline]
+    #[doc(alias = "class_addProtocol")]
+    pub fn add_protocol(&mut self, protocol: &Protocol) -> bool {
+        unsafe { class_addProtocol(self.class.as_ptr(), protocol) }.into()
+    }
+
+    /// Registers the class with the Objective-C runtime, after which it can be
+    /// used.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418603-objc_registerclasspair).
+    #[inline]
+    #[doc(alias = "objc_registerClassPair")]
+    pub fn register(self) -> &'static Class {
+        let class = self.class;
+        mem::forget(self);
+
+        unsafe {
+            objc_registerClassPair(class.as_ptr());
+            &*class.as_ptr()
+        }
+    }
+}
+
+extern "C" {
+    fn objc_allocateClassPair(
+        superclass: &Class,
+        name: *const c_char,
+        extra_bytes: usize,
+    ) -> *mut Class;
+    fn objc_registerClassPair(class: *mut Class);
+    fn objc_disposeClassPair(class: *mut Class);
+
+    fn class_addMethod(class: *mut Class, name: Sel, imp: Imp, types: *const c_char) -> BOOL;
+    fn class_addProtocol(class: *mut Class, protocol: &Protocol) -> BOOL;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::objc::{ClassType, NSObject};
+
+    #[test]
+    #[allow(clippy::manual_c_str_literals)] // `c""` literals require Rust 1.77.
+    fn add_protocol() {
+        let protocol_name = CStr::from_bytes_with_nul(b"NSCopying\0").unwrap();
+        let class_name = CStr::from_bytes_with_nul(b"FruityClassBuilderProtocol\0").unwrap();
+
+        let superclass = <NSObject as ClassType>::class();
+        let copying = Protocol::get(protocol_name).unwrap();
+        assert_eq!(copying.name(), protocol_name);
+
+        let mut builder = ClassBuilder::new(class_name, superclass).unwrap();
+        assert!(!builder.class().conforms_to_protocol(copying));
+
+        assert!(builder.add_protocol(copying));
+        assert!(!builder.add_protocol(copying));
+
+        let class = builder.register();
+        assert_eq!(class.name(), class_name);
+        assert!(class.is_subclass_of(superclass));
+        assert!(class.conforms_to_protocol(copying));
+
+        assert!(ClassBuilder::new(class_name, superclass).is_none());
+    }
+}
@@ -1,4 +1,6 @@
-use super::{Class, ObjectType};
+use super::{msg::MsgArgs, Class, ObjectType, Sel};
+use crate::core::Arc;
+use std::fmt;
 
 /// A type that represents an instance of a specific Objective-C class.
 ///
@@ -28,4 +30,146 @@ pub trait ClassType<'data>: ObjectType<'data> {
         let class = unsafe { <Self as ClassType>::direct_class() };
         class.as_object().class()
     }
+
+    /// Returns `true` if instances of this type implement or inherit a method
+    /// for `selector`.
+    ///
+    /// This is the class-level counterpart of
+    /// [`NSObject::responds_to_selector`](super::NSObject::responds_to_selector),
+    /// and is checked with
+    /// [`class_getInstanceMethod`](https://developer.apple.com/documentation/objectivec/1418530-class_getinstancemethod).
+    #[inline]
+    #[doc(alias = "class_getInstanceMethod")]
+    #[doc(alias = "instancesRespondToSelector")]
+    #[doc(alias = "instancesRespondToSelector:")]
+    fn instances_respond_to(selector: Sel) -> bool {
+        <Self as ClassType>::class()
+            .get_instance_method(selector)
+            .is_some()
+    }
+
+    /// Allocates a new instance of `Self` that must be initialized before use.
+    ///
+    /// This is equivalent to `[MyClass alloc]`.
+    ///
+    /// The allocated instance cannot be accessed until it is initialized with
+    /// [`UninitArc::init`]:
+    ///
+    /// ```compile_fail
+    /// use fruity::objc::{ClassType, NSObject};
+    ///
+    /// let obj = NSObject::alloc();
+    /// obj.hash();
+    /// ```
+    #[inline]
+    fn alloc() -> UninitArc<Self>
+    where
+        Self: Sized,
+    {
+        // SAFETY: The instance is only accessible after initialization.
+        UninitArc(unsafe { <Self as ClassType>::class().alloc() })
+    }
+
+    /// Allocates a new instance of `Self` and initializes it by sending the
+    /// initializer `selector` with `args`.
+    ///
+    /// This is equivalent to `[[MyClass alloc] initWith...]`. The arguments are
+    /// passed as a tuple, such as `(bytes, length, encoding)` for
+    /// `initWithBytes:length:encoding:`.
+    ///
+    /// # Safety
+    ///
+    /// `selector` must be an initializer of `Self` whose parameter types match
+    /// `args` and that returns an instance of `Self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the initializer returns `nil`.
+    #[inline]
+    unsafe fn alloc_init_with<A>(selector: Sel, args: A) -> Arc<Self>
+    where
+        Self: Sized,
+        A: MsgArgs,
+    {
+        let value = Self::alloc();
+
+        // The initializer consumes the allocated instance.
+        let value = (&*Arc::into_raw(value.0)).as_objc_object();
+
+        let value: Option<Arc<Self>> = value._msg_send_strict_with(selector, args);
+        value.expect("initializer returned nil")
+    }
+}
+
+/// An allocated but uninitialized instance of `T`.
+///
+/// This is created by [`ClassType::alloc`]. The instance can only be used once
+/// it has been initialized, which consumes `self` and returns an [`Arc`].
+///
+/// Dropping this without initializing it releases the allocated instance.
+pub struct UninitArc<T: crate::core::ObjectType>(Arc<T>);
+
+impl<T: crate::core::ObjectType> fmt::Debug for UninitArc<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("UninitArc")
+            .field(&format_args!("{:p}", self.0))
+            .finish()
+    }
+}
+
+impl<'data, T: ClassType<'data>> UninitArc<T> {
+    /// Initializes the instance with `-[NSObject init]`.
+    ///
+    /// This is equivalent to `[[MyClass alloc] init]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the initializer returns `nil`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/nsobject/1418641-init).
+    #[inline]
+    pub fn init(self) -> Arc<T> {
+        // The initializer consumes the allocated instance.
+        let value = unsafe { &*Arc::into_raw(self.0) }.as_objc_object();
+
+        let value: Option<Arc<T>> = unsafe { _msg_send_strict![value, init] };
+        value.expect("initializer returned nil")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::objc::NSObject;
+
+    #[test]
+    fn instances_respond_to() {
+        use crate::foundation::NSString;
+
+        assert!(NSString::instances_respond_to(selector!(length)));
+        assert!(!NSString::instances_respond_to(selector!(fruityFabricated)));
+    }
+
+    #[test]
+    fn alloc_init_with() {
+        use crate::foundation::{NSString, NSStringEncoding};
+        use crate::objc::NSUInteger;
+
+        let s = "fruity 🦀";
+        let string: Arc<NSString> = unsafe {
+            NSString::alloc_init_with(
+                selector!(initWithBytes:length:encoding:),
+                (s.as_ptr(), s.len() as NSUInteger, NSStringEncoding::UTF8),
+            )
+        };
+        assert_eq!(*string, *NSString::from_str(s));
+    }
+
+    #[test]
+    fn alloc_init() {
+        let obj = NSObject::alloc().init();
+        assert!(obj.is_kind_of_class(<NSObject as ClassType>::class()));
+        assert_eq!(obj.retain_count(), 1);
+    }
 }
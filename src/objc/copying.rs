@@ -0,0 +1,85 @@
+use super::{ClassType, ObjectType};
+use crate::core::Arc;
+
+/// A class whose instances can be copied with
+/// [`NSCopying`](https://developer.apple.com/documentation/foundation/nscopying).
+///
+/// # Safety
+///
+/// Instances of `Self` must respond to `copy` by returning an instance of
+/// `Self`. Classes with a mutable subclass (such as `NSString`) satisfy this
+/// because copying a mutable instance returns an immutable one.
+///
+/// # Related Items
+///
+/// - [`objc::MutableCopying`](crate::objc::MutableCopying)
+pub unsafe trait Copying<'data>: ClassType<'data> + Sized {
+    /// Returns a copy of this object.
+    ///
+    /// For immutable objects, this may return `self` retained.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/nsobject/1418807-copy).
+    #[inline]
+    fn copy(&self) -> Arc<Self> {
+        unsafe { _msg_send_strict![self.as_objc_object(), copy] }
+    }
+}
+
+/// A class whose instances can be copied into a mutable counterpart with
+/// [`NSMutableCopying`](https://developer.apple.com/documentation/foundation/nsmutablecopying).
+///
+/// # Safety
+///
+/// Instances of `Self` must respond to `mutableCopy` by returning an instance
+/// of [`Self::Mutable`](MutableCopying::Mutable).
+///
+/// # Related Items
+///
+/// - [`objc::Copying`](crate::objc::Copying)
+pub unsafe trait MutableCopying<'data>: ObjectType<'data> {
+    /// The mutable type returned by
+    /// [`mutable_copy`](MutableCopying::mutable_copy).
+    type Mutable: ObjectType<'data>;
+
+    /// Returns a new mutable copy of this object.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/nsobject/1418978-mutablecopy).
+    #[inline]
+    fn mutable_copy(&self) -> Arc<Self::Mutable> {
+        unsafe { _msg_send_strict![self.as_objc_object(), mutableCopy] }
+    }
+}
+
+#[cfg(all(test, feature = "foundation"))]
+mod tests {
+    use super::*;
+    use crate::foundation::{NSArray, NSNumber, NSString};
+
+    #[test]
+    fn array_mutable_copy() {
+        let numbers = [1, 2, 3].map(NSNumber::from_int);
+        let array = NSArray::from_slice(&numbers);
+
+        let copy = array.mutable_copy();
+        copy.add_object(&NSNumber::from_int(4));
+
+        assert_eq!(array.count(), 3);
+        assert_eq!(copy.count(), 4);
+
+        let copy = copy.copy();
+        assert_eq!(copy.count(), 4);
+    }
+
+    #[test]
+    fn string_mutable_copy() {
+        let string = NSString::from_str("fruity");
+
+        let copy = string.mutable_copy();
+        copy.append_str("🦀");
+
+        assert_eq!(string.to_string(), "fruity");
+        assert_eq!(copy.to_string(), "fruity🦀");
+        assert_eq!(copy.mutable_copy().to_string(), "fruity🦀");
+        assert_eq!(string.copy().to_string(), "fruity");
+    }
+}
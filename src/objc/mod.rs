@@ -22,9 +22,12 @@ mod macros_pub;
 mod msg;
 
 mod autoreleasepool;
+mod block;
 mod bool;
 mod class;
+mod class_builder;
 mod class_type;
+mod copying;
 mod image_info;
 mod int;
 mod ivar;
@@ -33,20 +36,26 @@ mod ns_object;
 mod objc_object;
 mod object_type;
 mod property;
+mod protocol;
 mod type_encoding;
 
 pub use self::bool::*;
 pub use autoreleasepool::*;
+pub(crate) use block::StackBlock;
 pub use class::*;
+pub use class_builder::*;
 pub use class_type::*;
+pub use copying::*;
 pub use image_info::*;
 pub use int::*;
 pub use ivar::*;
 pub use method::*;
+pub use msg::MsgArgs;
 pub use ns_object::*;
 pub use objc_object::*;
 pub use object_type::*;
 pub use property::*;
+pub use protocol::*;
 pub use sel::Sel;
 pub use type_encoding::*;
 
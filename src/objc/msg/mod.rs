@@ -118,21 +118,32 @@ where {
     }
 }
 
-// This trait is intentionally undocumented to ensure it is not publicly
-// exported.
+mod private {
+    pub trait Sealed {}
+}
+
+/// A tuple of arguments that can be passed in an Objective-C message, such as
+/// to [`ClassType::alloc_init_with`](super::ClassType::alloc_init_with).
+///
+/// This is implemented for tuples of up to 12 elements. It is sealed and
+/// cannot be implemented outside of this crate.
 #[deny(missing_docs)]
-pub trait MsgArgs: Sized {
+pub trait MsgArgs: Sized + private::Sealed {
     /// Dispatches the appropriate version of `objc_msgSend` based on the return
     /// type.
+    #[doc(hidden)]
     unsafe fn msg_send_any<Ret: 'static>(obj: *const c_void, sel: Sel, args: Self) -> Ret;
 
     /// Dispatches only to `objc_msgSend`.
+    #[doc(hidden)]
     unsafe fn msg_send_strict<Ret>(obj: *const c_void, sel: Sel, args: Self) -> Ret;
 }
 
 /// Implements `MsgArgs` for tuples of different sizes.
 macro_rules! impl_msg_args_base {
     ($($arg:ident),*) => {
+        impl<$($arg,)*> private::Sealed for ($($arg,)*) {}
+
         impl<$($arg,)*> MsgArgs for ($($arg,)*) {
             #[inline]
             #[allow(non_snake_case)]
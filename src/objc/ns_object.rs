@@ -1,6 +1,11 @@
 use super::{Class, ClassType, NSUInteger, ObjCObject, Sel, BOOL};
 use crate::core::Arc;
 use crate::foundation::NSString;
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
 
 // TODO: Create `NSObjectProtocol` for `@protocol NSObject` and `Deref` to that.
 objc_subclass! {
@@ -27,11 +32,15 @@ impl PartialEq for NSObject<'_> {
 impl<'data> NSObject<'data> {
     /// Returns this object's reference count.
     ///
-    /// This method is only useful for debugging certain objects.
+    /// This method is only useful for debugging certain objects. The result is
+    /// unreliable for singletons, tagged pointers, and objects retained by
+    /// autorelease pools or the frameworks, which may report arbitrary or
+    /// constant counts.
     ///
     /// See [documentation](https://developer.apple.com/documentation/objectivec/1418956-nsobject/1571952-retaincount).
     #[inline]
-    pub fn retain_count(&self) -> usize {
+    #[doc(alias = "retainCount")]
+    pub fn retain_count(&self) -> NSUInteger {
         unsafe { _msg_send_any_cached![self, retainCount] }
     }
 
@@ -60,6 +69,39 @@ impl<'data> NSObject<'data> {
         unsafe { _msg_send_any_cached![self, isMemberOfClass: class => BOOL] }.into()
     }
 
+    /// Returns `true` if this object is a proxy, such as an `NSProxy`, rather
+    /// than an object that inherits from `NSObject`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418956-nsobject/1418528-isproxy).
+    #[inline]
+    #[doc(alias = "isProxy")]
+    pub fn is_proxy(&self) -> bool {
+        unsafe { _msg_send_any![self, isProxy => BOOL] }.into()
+    }
+
+    /// Returns `true` if this object is a
+    /// [tagged pointer](https://en.wikipedia.org/wiki/Tagged_pointer), whose
+    /// value is stored in the pointer itself rather than in allocated memory.
+    ///
+    /// This is a best-effort check based on the tag bit of the platform ABI.
+    /// Tagged pointers are never deallocated, so their
+    /// [`retain_count`](Self::retain_count) is meaningless.
+    #[inline]
+    pub fn is_tagged_pointer(&self) -> bool {
+        // The Objective-C runtime only uses tagged pointers on 64-bit
+        // platforms. Intel macOS uses the least significant bit and all others
+        // use the most significant bit.
+        const TAG_MASK: usize = if cfg!(target_pointer_width = "32") {
+            0
+        } else if cfg!(all(target_os = "macos", target_arch = "x86_64")) {
+            1
+        } else {
+            1 << (usize::BITS - 1)
+        };
+
+        (self as *const Self as usize) & TAG_MASK != 0
+    }
+
     /// Returns an integer that can be used as a table address in a hash table
     /// structure.
     ///
@@ -107,3 +149,127 @@ impl<'data> NSObject<'data> {
         unsafe { _msg_send_any![self, debugDescription] }
     }
 }
+
+/// An object wrapper that implements [`Eq`] and [`Hash`] using Objective-C
+/// object equality semantics.
+///
+/// Comparisons use
+/// [`-isEqual:`](https://developer.apple.com/documentation/objectivec/1418956-nsobject/1418795-isequal)
+/// and hashing uses
+/// [`-hash`](https://developer.apple.com/documentation/objectivec/1418956-nsobject/1418859-hash).
+/// This allows for using arbitrary objects as keys in collections like
+/// [`HashSet`](std::collections::HashSet) and
+/// [`HashMap`](std::collections::HashMap), deduplicated in the same way as
+/// Cocoa collections.
+///
+/// Objects must not be mutated in a way that changes their hash while in a
+/// collection.
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct EqObject<T: crate::core::ObjectType>(pub Arc<T>);
+
+impl<T: crate::core::ObjectType> From<Arc<T>> for EqObject<T> {
+    #[inline]
+    fn from(obj: Arc<T>) -> Self {
+        Self(obj)
+    }
+}
+
+impl<T: crate::core::ObjectType> Deref for EqObject<T> {
+    type Target = Arc<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: crate::core::ObjectType + fmt::Debug> fmt::Debug for EqObject<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (*self.0).fmt(f)
+    }
+}
+
+impl<'data, T: super::ObjectType<'data>> PartialEq for EqObject<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        let this = self.0.as_objc_object();
+        let other = other.0.as_objc_object();
+        unsafe { _msg_send_any_cached![this, isEqual: other => BOOL] }.into()
+    }
+}
+
+impl<'data, T: super::ObjectType<'data>> Eq for EqObject<T> {}
+
+impl<'data, T: super::ObjectType<'data>> Hash for EqObject<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let this = self.0.as_objc_object();
+        let hash: NSUInteger = unsafe { _msg_send_any_cached![this, hash] };
+        hash.hash(state);
+    }
+}
+
+impl<T: crate::core::ObjectType> EqObject<T> {
+    /// Wraps `obj` for use with Objective-C equality semantics.
+    #[inline]
+    pub fn new(obj: Arc<T>) -> Self {
+        Self(obj)
+    }
+
+    /// Returns the wrapped object.
+    #[inline]
+    pub fn into_inner(this: Self) -> Arc<T> {
+        this.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::foundation::NSNumber;
+    use std::collections::HashSet;
+
+    #[test]
+    fn retain_count() {
+        // A plain `NSObject` instance is neither a singleton nor a tagged
+        // pointer, so its count is meaningful.
+        let obj = Arc::<NSObject>::default();
+        let count = obj.retain_count();
+
+        let clone = obj.clone();
+        assert_eq!(obj.retain_count(), count + 1);
+
+        drop(clone);
+        assert_eq!(obj.retain_count(), count);
+    }
+
+    #[test]
+    fn is_proxy() {
+        assert!(!Arc::<NSObject>::default().is_proxy());
+    }
+
+    #[test]
+    fn is_tagged_pointer() {
+        assert!(!Arc::<NSObject>::default().is_tagged_pointer());
+
+        #[cfg(target_pointer_width = "64")]
+        assert!(NSNumber::from_int(1).is_tagged_pointer());
+    }
+
+    #[test]
+    fn eq_object_dedup() {
+        let numbers = [
+            NSNumber::from_int(1),
+            NSNumber::from_int(2),
+            NSNumber::from_int(1),
+            NSNumber::from_int(2),
+            NSNumber::from_int(3),
+        ];
+
+        let set: HashSet<EqObject<NSNumber>> = numbers.iter().cloned().map(EqObject).collect();
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&EqObject(NSNumber::from_int(3))));
+    }
+}
@@ -1,4 +1,4 @@
-use super::{sys, Class, Ivar, ObjectType, Sel, BOOL};
+use super::{sys, Class, Ivar, NSObject, ObjectType, Sel, BOOL};
 use crate::core::Arc;
 use std::{
     alloc,
@@ -100,6 +100,37 @@ impl fmt::Debug for ObjCObject<'_> {
     }
 }
 
+impl<'data> ObjCObject<'data> {
+    /// Sends a message that returns an object and retains the result.
+    ///
+    /// This is for methods that follow the +0 (autoreleased) return
+    /// convention, which are most methods. The returned object is retained so
+    /// that it remains valid after the enclosing
+    /// [autorelease pool](super::autoreleasepool) is drained.
+    ///
+    /// Returns `None` if the message returns `nil`.
+    ///
+    /// `args` is a tuple of arguments, such as `()` for no arguments or
+    /// `(a,)` for one argument.
+    ///
+    /// # Safety
+    ///
+    /// The types of `args` must match the parameter types of the method for
+    /// `sel`, and the method must return an object.
+    ///
+    /// The method must not belong to the `alloc`, `copy`, `mutableCopy`, or
+    /// `new` method families, which return +1 (owned) objects. Otherwise, the
+    /// returned object will be leaked.
+    #[inline]
+    pub unsafe fn send_returning_object<A>(&self, sel: Sel, args: A) -> Option<Arc<NSObject<'data>>>
+    where
+        A: super::msg::MsgArgs,
+    {
+        let obj: Option<&NSObject<'data>> = self._msg_send_strict_with(sel, args);
+        obj.map(Arc::retain)
+    }
+}
+
 impl ObjCObject<'_> {
     /// Returns `true` if this class implements or inherits a method that can
     /// respond to a specified message.
@@ -111,6 +142,13 @@ impl ObjCObject<'_> {
         unsafe { _msg_send_any_cached![self, respondsToSelector: selector => BOOL] }.into()
     }
 
+    /// Returns the object's reference count, as used by
+    /// [`Arc::is_unique`](crate::core::Arc::is_unique).
+    #[inline]
+    pub(crate) fn retain_count(&self) -> super::NSUInteger {
+        unsafe { _msg_send_any_cached![self, retainCount] }
+    }
+
     /// Changes the value of an instance variable of a class instance and
     /// returns its [`Ivar`].
     ///
@@ -146,3 +184,21 @@ impl ObjCObject<'_> {
         &*sys::object_setInstanceVariable(self, name.as_ptr(), value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::objc::autoreleasepool;
+
+    #[test]
+    fn send_returning_object() {
+        let obj = Arc::<NSObject>::default();
+
+        let description =
+            autoreleasepool(|| unsafe { obj.send_returning_object(selector!(description), ()) })
+                .unwrap();
+
+        // The retained string remains valid after its pool was drained.
+        assert!(description.responds_to_selector(selector!(length)));
+    }
+}
@@ -0,0 +1,76 @@
+use std::{ffi::CStr, fmt, os::raw::c_char, ptr};
+
+/// An Objective-C protocol declaration.
+///
+/// See [documentation](https://developer.apple.com/documentation/objectivec/protocol).
+///
+/// # Usage
+///
+/// This is an opaque type meant to be used behind a shared reference
+/// `&Protocol`, which is semantically equivalent to `Protocol * _Nonnull`.
+///
+/// A nullable protocol is defined as `Option<&Protocol>`, which is
+/// semantically equivalent to `Protocol * _Nullable`.
+#[repr(C)]
+pub struct Protocol {
+    data: [u8; 0],
+}
+
+impl fmt::Debug for Protocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Protocol").field(&self.name()).finish()
+    }
+}
+
+impl PartialEq for Protocol {
+    #[inline]
+    #[doc(alias = "protocol_isEqual")]
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self, other)
+    }
+}
+
+impl Eq for Protocol {}
+
+impl Protocol {
+    /// Returns the protocol with `name`, or `None` if it is not known to the
+    /// Objective-C runtime.
+    ///
+    /// Protocols are only registered if they are referenced by loaded code,
+    /// such as through `@protocol(...)` in Objective-C.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418870-objc_getprotocol).
+    #[inline]
+    #[doc(alias = "objc_getProtocol")]
+    pub fn get(name: &CStr) -> Option<&'static Protocol> {
+        extern "C" {
+            fn objc_getProtocol(name: *const c_char) -> Option<&'static Protocol>;
+        }
+        unsafe { objc_getProtocol(name.as_ptr()) }
+    }
+
+    /// Returns the name of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418909-protocol_getname).
+    #[inline]
+    #[doc(alias = "protocol_getName")]
+    pub fn name(&self) -> &CStr {
+        extern "C" {
+            fn protocol_getName(protocol: &Protocol) -> *const c_char;
+        }
+        unsafe { CStr::from_ptr(protocol_getName(self)) }
+    }
+
+    /// Returns `true` if `self` conforms to `other`, either directly or
+    /// through the protocols it incorporates.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418893-protocol_conformstoprotocol).
+    #[inline]
+    #[doc(alias = "protocol_conformsToProtocol")]
+    pub fn conforms_to(&self, other: &Protocol) -> bool {
+        extern "C" {
+            fn protocol_conformsToProtocol(protocol: &Protocol, other: &Protocol) -> super::BOOL;
+        }
+        unsafe { protocol_conformsToProtocol(self, other) }.into()
+    }
+}
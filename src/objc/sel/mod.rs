@@ -2,6 +2,7 @@ use super::BOOL;
 use std::{
     ffi::CStr,
     fmt,
+    hash::{Hash, Hasher},
     os::raw::{c_char, c_void},
     ptr::NonNull,
 };
@@ -38,6 +39,15 @@ impl PartialEq for Sel {
 
 impl Eq for Sel {}
 
+impl Hash for Sel {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Selectors are uniqued by the runtime, so equal selectors have the
+        // same address.
+        self.0.hash(state);
+    }
+}
+
 impl fmt::Debug for Sel {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -101,3 +111,19 @@ extern "C" {
     fn sel_registerName(name: *const c_char) -> Sel;
     fn sel_getName(sel: Sel) -> *const c_char;
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    #[test]
+    fn hash_map_key() {
+        let mut map = HashMap::new();
+        map.insert(selector!(init), 1);
+        map.insert(selector!(initWithObjects:count:), 2);
+
+        assert_eq!(map.get(&selector!(init)), Some(&1));
+        assert_eq!(map.get(&selector!(initWithObjects:count:)), Some(&2));
+        assert_eq!(map.get(&selector!(dealloc)), None);
+    }
+}
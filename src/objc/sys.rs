@@ -53,4 +53,14 @@ extern "C" {
     pub fn ivar_getTypeEncoding(ivar: *const Ivar) -> *const c_char;
 
     pub fn NSGetSizeAndAlignment(ty: *const c_char, size: *mut NSUInteger, align: *mut NSUInteger);
+
+    pub fn objc_initWeak(
+        location: *mut *mut ObjCObject<'static>,
+        obj: *const ObjCObject,
+    ) -> *mut ObjCObject<'static>;
+    pub fn objc_loadWeakRetained(
+        location: *mut *mut ObjCObject<'static>,
+    ) -> *mut ObjCObject<'static>;
+    pub fn objc_copyWeak(to: *mut *mut ObjCObject<'static>, from: *mut *mut ObjCObject<'static>);
+    pub fn objc_destroyWeak(location: *mut *mut ObjCObject<'static>);
 }